@@ -12,6 +12,26 @@ pub fn linking_symbol(name: &str) -> String {
     format!("__component_type_object_force_link_{snake}")
 }
 
+/// Returns the name of the custom section which contains the component type
+/// for the world `world_name`.
+pub fn section_name(world_name: &str, suffix: Option<&str>) -> String {
+    // The custom section name here must start with "component-type" but
+    // otherwise is attempted to be unique here to ensure that this doesn't get
+    // concatenated to other custom sections by LLD by accident since LLD will
+    // concatenate custom sections of the same name.
+    format!("component-type:{world_name}{}", suffix.unwrap_or(""))
+}
+
+fn encode(resolve: &Resolve, world: WorldId, encoding: StringEncoding) -> Result<Vec<u8>> {
+    let mut producers = wasm_metadata::Producers::empty();
+    producers.add(
+        "processed-by",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+    );
+    wit_component::metadata::encode(resolve, world, encoding, Some(&producers))
+}
+
 pub fn object(
     resolve: &Resolve,
     world: WorldId,
@@ -34,19 +54,8 @@ pub fn object(
     code.function(&func);
     module.section(&code);
 
-    let mut producers = wasm_metadata::Producers::empty();
-    producers.add(
-        "processed-by",
-        env!("CARGO_PKG_NAME"),
-        env!("CARGO_PKG_VERSION"),
-    );
-    let data = wit_component::metadata::encode(resolve, world, encoding, Some(&producers)).unwrap();
-
-    // The custom section name here must start with "component-type" but
-    // otherwise is attempted to be unique here to ensure that this doesn't get
-    // concatenated to other custom sections by LLD by accident since LLD will
-    // concatenate custom sections of the same name.
-    let section_name = format!("component-type:{world_name}{}", suffix.unwrap_or(""));
+    let data = encode(resolve, world, encoding)?;
+    let section_name = section_name(world_name, suffix);
 
     // Add our custom section
     module.section(&CustomSection {
@@ -63,3 +72,84 @@ pub fn object(
 
     Ok(module.finish())
 }
+
+/// Returns C source for a byte array which places the component type for
+/// `world` into its custom section, as an alternative to linking in the object
+/// file produced by [`object`].
+pub fn c_array(
+    resolve: &Resolve,
+    world: WorldId,
+    world_name: &str,
+    encoding: StringEncoding,
+    suffix: Option<&str>,
+) -> Result<String> {
+    let data = encode(resolve, world, encoding)?;
+    let section_name = section_name(world_name, suffix);
+    let snake = world_name.to_snake_case();
+    let mut src = String::new();
+    src.push_str(&format!(
+        "__attribute__((used, section(\".custom_section.{section_name}\")))\n\
+         static const unsigned char __component_type_{snake}[{}] = {{\n",
+        data.len()
+    ));
+    for chunk in data.chunks(16) {
+        src.push_str("   ");
+        for byte in chunk {
+            src.push_str(&format!(" 0x{byte:02x},"));
+        }
+        src.push_str("\n");
+    }
+    src.push_str("};\n");
+    Ok(src)
+}
+
+/// Returns the source of a C file which embeds the component type for `world`
+/// and defines the symbol which the generated bindings use to force the
+/// component type to be linked in.
+///
+/// This is an alternative to [`object`] for toolchains that can't consume the
+/// relocatable wasm object file produced there.
+pub fn c_source(
+    resolve: &Resolve,
+    world: WorldId,
+    world_name: &str,
+    encoding: StringEncoding,
+    suffix: Option<&str>,
+) -> Result<String> {
+    let mut src = c_array(resolve, world, world_name, encoding, suffix)?;
+    let linking_symbol = linking_symbol(world_name);
+    src.push_str(&format!("\nvoid {linking_symbol}(void) {{}}\n"));
+    Ok(src)
+}
+
+/// Same as [`c_source`], but returns the contents of an assembly file instead.
+pub fn assembly(
+    resolve: &Resolve,
+    world: WorldId,
+    world_name: &str,
+    encoding: StringEncoding,
+    suffix: Option<&str>,
+) -> Result<String> {
+    let data = encode(resolve, world, encoding)?;
+    let section_name = section_name(world_name, suffix);
+    let linking_symbol = linking_symbol(world_name);
+    let mut src = String::new();
+    src.push_str(&format!(
+        "\t.text\n\
+         \t.globl\t{linking_symbol}\n\
+         \t.type\t{linking_symbol},@function\n\
+         {linking_symbol}:\n\
+         \t.functype\t{linking_symbol} () -> ()\n\
+         \tend_function\n\
+         \n\
+         \t.section\t\".custom_section.{section_name}\",\"\",@\n"
+    ));
+    for chunk in data.chunks(16) {
+        let bytes = chunk
+            .iter()
+            .map(|byte| format!("0x{byte:02x}"))
+            .collect::<Vec<_>>();
+        src.push_str(&format!("\t.byte\t{}\n", bytes.join(", ")));
+    }
+    Ok(src)
+}
@@ -40,6 +40,16 @@ struct C {
     type_names: HashMap<TypeId, String>,
     resources: HashMap<TypeId, ResourceInfo>,
     futures: IndexSet<TypeId>,
+
+    /// The per-interface header currently being generated, if headers are
+    /// split per interface.
+    current_header: Option<String>,
+    /// Header that each type name was defined in when headers are split.
+    type_headers: HashMap<String, String>,
+    /// Headers defining types referenced from `current_header`.
+    header_deps: IndexSet<String>,
+    /// Finished per-interface headers along with the headers they depend on.
+    interface_headers: Vec<(String, IndexSet<String>, wit_bindgen_core::Source)>,
 }
 
 #[derive(Default)]
@@ -67,6 +77,31 @@ impl std::fmt::Display for Enabled {
     }
 }
 
+/// How the component type of the world is emitted alongside the bindings.
+#[derive(Default, Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum ComponentTypeFormat {
+    /// A relocatable wasm object file, `<world>_component_type.o`, which
+    /// requires `wasm-ld` to link.
+    #[default]
+    Object,
+    /// An assembly file, `<world>_component_type.s`.
+    Assembly,
+    /// A C source file, `<world>_component_type.c`, which places the component
+    /// type in its custom section through a `section` attribute.
+    C,
+}
+
+impl std::fmt::Display for ComponentTypeFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Object => write!(f, "object"),
+            Self::Assembly => write!(f, "assembly"),
+            Self::C => write!(f, "c"),
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone)]
 #[cfg_attr(feature = "clap", derive(clap::Parser))]
 pub struct Opts {
@@ -95,6 +130,35 @@ pub struct Opts {
     #[cfg_attr(feature = "clap", arg(long, default_value_t = false))]
     pub no_object_file: bool,
 
+    /// Configure the format of the file which contains type information for
+    /// the world that is being generated.
+    #[cfg_attr(
+        feature = "clap",
+        arg(
+            long,
+            default_value_t = ComponentTypeFormat::default(),
+            value_name = "FORMAT",
+        ),
+    )]
+    pub component_type_format: ComponentTypeFormat,
+
+    /// Emit all bindings into a single stb-style `<world>.h` header.
+    ///
+    /// Exactly one translation unit must define `<WORLD>_IMPLEMENTATION`
+    /// before including the header to get the implementation. The component
+    /// type is embedded in the implementation instead of being emitted as a
+    /// separate object file.
+    #[cfg_attr(feature = "clap", arg(long, default_value_t = false))]
+    pub single_header: bool,
+
+    /// Place the declarations of each interface in its own header.
+    ///
+    /// `<world>.h` then includes one `<world>_<interface>.h` header per
+    /// interface, and each of those includes the headers of the interfaces
+    /// whose types it uses. This has no effect with `--single-header`.
+    #[cfg_attr(feature = "clap", arg(long, default_value_t = false))]
+    pub split_interfaces: bool,
+
     /// Rename the interface `K` to `V` in the generated source code.
     #[cfg_attr(feature = "clap", arg(long, name = "K=V", value_parser = parse_rename))]
     pub rename: Vec<(String, String)>,
@@ -215,6 +279,7 @@ impl WorldGenerator for C {
         _files: &mut Files,
     ) -> Result<()> {
         let wasm_import_module = resolve.name_world_key(name);
+        self.start_interface_header(resolve, name, true);
         let mut r#gen = self.interface(resolve, true, Some(&wasm_import_module));
         r#gen.interface = Some((id, name));
         r#gen.define_interface_types(id);
@@ -228,7 +293,8 @@ impl WorldGenerator for C {
             r#gen.import(Some(name), func);
         }
 
-        r#gen.r#gen.src.append(&r#gen.src);
+        let src = mem::take(&mut r#gen.src);
+        self.finish_interface(src);
 
         Ok(())
     }
@@ -262,6 +328,7 @@ impl WorldGenerator for C {
         id: InterfaceId,
         _files: &mut Files,
     ) -> Result<()> {
+        self.start_interface_header(resolve, name, false);
         let mut r#gen = self.interface(resolve, false, None);
         r#gen.interface = Some((id, name));
         r#gen.define_interface_types(id);
@@ -275,7 +342,8 @@ impl WorldGenerator for C {
             r#gen.export(func, Some(name));
         }
 
-        r#gen.r#gen.src.append(&r#gen.src);
+        let src = mem::take(&mut r#gen.src);
+        self.finish_interface(src);
         Ok(())
     }
 
@@ -322,20 +390,22 @@ impl WorldGenerator for C {
         let linking_symbol = component_type_object::linking_symbol(&self.world);
         self.c_include("<stdlib.h>");
         let snake = self.world.to_snake_case();
-        uwriteln!(
-            self.src.c_adapters,
-            "\n// Ensure that the *_component_type.o object is linked in"
-        );
-        uwrite!(
-            self.src.c_adapters,
-            "
-               extern void {linking_symbol}(void);
-               __attribute__((used))
-               void {linking_symbol}_public_use_in_this_compilation_unit(void) {{
-                   {linking_symbol}();
-               }}
-           ",
-        );
+        if !self.opts.single_header {
+            uwriteln!(
+                self.src.c_adapters,
+                "\n// Ensure that the *_component_type object is linked in"
+            );
+            uwrite!(
+                self.src.c_adapters,
+                "
+                   extern void {linking_symbol}(void);
+                   __attribute__((used))
+                   void {linking_symbol}_public_use_in_this_compilation_unit(void) {{
+                       {linking_symbol}();
+                   }}
+               ",
+            );
+        }
 
         self.print_intrinsics();
 
@@ -457,22 +527,15 @@ impl WorldGenerator for C {
             self.generate_threading_helpers();
         }
         let version = env!("CARGO_PKG_VERSION");
-        let mut h_str = wit_bindgen_core::Source::default();
-
-        wit_bindgen_core::generated_preamble(&mut h_str, version);
-
-        uwrite!(
-            h_str,
-            "#ifndef __BINDINGS_{0}_H
-            #define __BINDINGS_{0}_H
-            #ifdef __cplusplus
-            extern \"C\" {{",
-            self.world.to_shouty_snake_case(),
-        );
-
-        // Deindent the extern C { declaration
-        h_str.deindent(1);
-        uwriteln!(h_str, "\n#endif\n");
+        let shouty = self.world.to_shouty_snake_case();
+        let split_interfaces = self.split_interfaces();
+        let mut h_str = if split_interfaces {
+            // Definitions shared by all interfaces are placed in their own
+            // header which every per-interface header includes.
+            header_prologue(&format!("{shouty}_COMMON"))
+        } else {
+            header_prologue(&shouty)
+        };
 
         uwriteln!(h_str, "#include <stdint.h>");
         uwriteln!(h_str, "#include <stdbool.h>");
@@ -482,8 +545,6 @@ impl WorldGenerator for C {
         }
 
         let mut c_str = wit_bindgen_core::Source::default();
-        wit_bindgen_core::generated_preamble(&mut c_str, version);
-        uwriteln!(c_str, "#include \"{snake}.h\"");
         for include in self.c_includes.iter() {
             uwriteln!(c_str, "#include {include}");
         }
@@ -526,6 +587,30 @@ impl WorldGenerator for C {
             h_str.push_str("\n");
         }
 
+        if split_interfaces {
+            header_epilogue(&mut h_str);
+            files.push(&format!("{snake}_common.h"), h_str.as_bytes());
+
+            let interface_headers = mem::take(&mut self.interface_headers);
+            for (header, deps, src) in interface_headers.iter() {
+                let guard = header.strip_suffix(".h").unwrap().to_shouty_snake_case();
+                let mut h = header_prologue(&guard);
+                uwriteln!(h, "#include \"{snake}_common.h\"");
+                for dep in deps.iter() {
+                    uwriteln!(h, "#include \"{dep}\"");
+                }
+                h.push_str(src);
+                header_epilogue(&mut h);
+                files.push(header, h.as_bytes());
+            }
+
+            h_str = header_prologue(&shouty);
+            uwriteln!(h_str, "#include \"{snake}_common.h\"");
+            for (header, _, _) in interface_headers.iter() {
+                uwriteln!(h_str, "#include \"{header}\"");
+            }
+        }
+
         if self.src.h_defs.len() > 0 {
             h_str.push_str(&self.src.h_defs);
         }
@@ -553,30 +638,71 @@ impl WorldGenerator for C {
 
         c_str.push_str(&self.src.c_adapters);
 
-        uwriteln!(
-            h_str,
-            "
-            #ifdef __cplusplus
-            }}
-            #endif
-            #endif"
-        );
+        header_epilogue(&mut h_str);
 
-        files.push(&format!("{snake}.h"), h_str.as_bytes());
-        files.push(&format!("{snake}.c"), c_str.as_bytes());
-        if !self.opts.no_object_file {
-            files.push(
-                &format!("{snake}_component_type.o",),
-                component_type_object::object(
+        if self.opts.single_header {
+            uwriteln!(
+                h_str,
+                "
+                #ifdef {shouty}_IMPLEMENTATION
+                #ifndef __BINDINGS_{shouty}_IMPLEMENTATION
+                #define __BINDINGS_{shouty}_IMPLEMENTATION"
+            );
+            h_str.push_str(&c_str);
+            if !self.opts.no_object_file {
+                uwriteln!(h_str, "\n// Component Type");
+                h_str.push_str(&component_type_object::c_array(
                     resolve,
                     id,
                     &self.world,
                     self.opts.string_encoding,
                     self.opts.type_section_suffix.as_deref(),
-                )
-                .unwrap()
-                .as_slice(),
+                )?);
+            }
+            uwriteln!(
+                h_str,
+                "
+                #endif
+                #endif"
             );
+            files.push(&format!("{snake}.h"), h_str.as_bytes());
+            return Ok(());
+        }
+
+        let mut c_file = wit_bindgen_core::Source::default();
+        wit_bindgen_core::generated_preamble(&mut c_file, version);
+        uwriteln!(c_file, "#include \"{snake}.h\"");
+        c_file.push_str(&c_str);
+
+        files.push(&format!("{snake}.h"), h_str.as_bytes());
+        files.push(&format!("{snake}.c"), c_file.as_bytes());
+        if !self.opts.no_object_file {
+            let encoding = self.opts.string_encoding;
+            let suffix = self.opts.type_section_suffix.as_deref();
+            match self.opts.component_type_format {
+                ComponentTypeFormat::Object => files.push(
+                    &format!("{snake}_component_type.o",),
+                    component_type_object::object(resolve, id, &self.world, encoding, suffix)?
+                        .as_slice(),
+                ),
+                ComponentTypeFormat::Assembly => files.push(
+                    &format!("{snake}_component_type.s",),
+                    component_type_object::assembly(resolve, id, &self.world, encoding, suffix)?
+                        .as_bytes(),
+                ),
+                ComponentTypeFormat::C => {
+                    let mut src = wit_bindgen_core::Source::default();
+                    wit_bindgen_core::generated_preamble(&mut src, version);
+                    src.push_str(&component_type_object::c_source(
+                        resolve,
+                        id,
+                        &self.world,
+                        encoding,
+                        suffix,
+                    )?);
+                    files.push(&format!("{snake}_component_type.c",), src.as_bytes());
+                }
+            }
         }
 
         Ok(())
@@ -605,6 +731,58 @@ impl C {
         }
     }
 
+    fn split_interfaces(&self) -> bool {
+        self.opts.split_interfaces && !self.opts.single_header
+    }
+
+    /// Starts collecting the header declarations of the interface `name` into
+    /// their own header, if headers are split per interface.
+    fn start_interface_header(&mut self, resolve: &Resolve, name: &WorldKey, in_import: bool) {
+        if !self.split_interfaces() {
+            return;
+        }
+        let ident = interface_identifier(name, resolve, !in_import, &self.renamed_interfaces);
+        let snake = self.world.to_snake_case();
+        self.current_header = Some(format!("{snake}_{ident}.h"));
+    }
+
+    /// Appends the bindings generated for an interface to the world's
+    /// sources, moving its header declarations into their own header if
+    /// `start_interface_header` started one.
+    fn finish_interface(&mut self, mut src: Source) {
+        if let Some(header) = self.current_header.take() {
+            let mut h = wit_bindgen_core::Source::default();
+            h.push_str(&mem::take(&mut src.h_defs));
+            h.push_str(&mem::take(&mut src.h_fns));
+            let h_helpers = mem::take(&mut src.h_helpers);
+            if !self.opts.no_helpers && h_helpers.len() > 0 {
+                uwriteln!(h, "\n// Helper Functions");
+                h.push_str(&h_helpers);
+                h.push_str("\n");
+            }
+            let deps = mem::take(&mut self.header_deps);
+            self.interface_headers.push((header, deps, h));
+        }
+        self.src.append(&src);
+    }
+
+    /// Records that the type `name` is defined in the current header.
+    fn record_type_header(&mut self, name: &str) {
+        if let Some(header) = &self.current_header {
+            self.type_headers.insert(name.to_string(), header.clone());
+        }
+    }
+
+    /// Records that the current header refers to the type `name`, so it must
+    /// include the header which defines it.
+    fn use_type_header(&mut self, name: &str) {
+        if let (Some(current), Some(header)) = (&self.current_header, self.type_headers.get(name)) {
+            if current != header {
+                self.header_deps.insert(header.clone());
+            }
+        }
+    }
+
     fn h_include(&mut self, s: &str) {
         self.h_includes.push(s.to_string());
     }
@@ -651,6 +829,8 @@ impl C {
             Type::Id(id) => {
                 if let Some(name) = self.type_names.get(id) {
                     dst.push_str(name);
+                    let name = name.clone();
+                    self.use_type_header(&name);
                     return;
                 }
 
@@ -1122,6 +1302,38 @@ pub fn push_ty_name(resolve: &Resolve, ty: &Type, src: &mut String) {
     }
 }
 
+/// Starts a header with the include guard `__BINDINGS_{guard}_H`, opening an
+/// `extern "C"` block for C++.
+fn header_prologue(guard: &str) -> wit_bindgen_core::Source {
+    let mut h_str = wit_bindgen_core::Source::default();
+    wit_bindgen_core::generated_preamble(&mut h_str, env!("CARGO_PKG_VERSION"));
+
+    uwrite!(
+        h_str,
+        "#ifndef __BINDINGS_{guard}_H
+        #define __BINDINGS_{guard}_H
+        #ifdef __cplusplus
+        extern \"C\" {{",
+    );
+
+    // Deindent the extern C { declaration
+    h_str.deindent(1);
+    uwriteln!(h_str, "\n#endif\n");
+    h_str
+}
+
+/// Closes a header started with `header_prologue`.
+fn header_epilogue(h_str: &mut wit_bindgen_core::Source) {
+    uwriteln!(
+        h_str,
+        "
+        #ifdef __cplusplus
+        }}
+        #endif
+        #endif"
+    );
+}
+
 pub fn owner_namespace<'a>(
     interface: Option<(InterfaceId, &'a WorldKey)>,
     in_import: bool,
@@ -1507,6 +1719,8 @@ void __wasm_export_{ns}_{snake}_dtor({ns}_{snake}_t* arg) {{
             ));
         }
 
+        self.r#gen.record_type_header(&own);
+        self.r#gen.record_type_header(&borrow);
         self.r#gen.resources.insert(
             id,
             ResourceInfo {
@@ -1729,10 +1943,12 @@ impl<'a> wit_bindgen_core::AnonymousTypeGenerator<'a> for InterfaceGenerator<'a>
             Handle::Borrow(id) | Handle::Own(id) => id,
         };
         let info = &self.r#gen.resources[&dealias(self.resolve, *resource)];
-        match handle {
-            Handle::Borrow(_) => self.src.h_defs(&info.borrow),
-            Handle::Own(_) => self.src.h_defs(&info.own),
-        }
+        let name = match handle {
+            Handle::Borrow(_) => info.borrow.clone(),
+            Handle::Own(_) => info.own.clone(),
+        };
+        self.r#gen.use_type_header(&name);
+        self.src.h_defs(&name);
         self.src.h_defs(" ");
         self.print_typedef_target(id);
     }
@@ -1884,6 +2100,7 @@ impl InterfaceGenerator<'_> {
                     let typedef_name = format!("{}_{encoded}_t", self.owner_namespace(ty));
                     let prev = self.r#gen.type_names.insert(ty, typedef_name.clone());
                     assert!(prev.is_none());
+                    self.r#gen.record_type_header(&typedef_name);
 
                     self.define_type(name, ty)
                 }
@@ -1899,12 +2116,13 @@ impl InterfaceGenerator<'_> {
                         (false, format!("{namespace}_{encoded}_t"))
                     };
 
-                    let prev = self.r#gen.type_names.insert(ty, name);
+                    let prev = self.r#gen.type_names.insert(ty, name.clone());
                     assert!(prev.is_none());
 
                     if defined {
                         continue;
                     }
+                    self.r#gen.record_type_header(&name);
 
                     let kind = &self.resolve.types[ty].kind;
                     if let TypeDefKind::Handle(handle) = kind {
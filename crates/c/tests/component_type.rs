@@ -0,0 +1,73 @@
+use std::process::Command;
+use wit_bindgen_c::{ComponentTypeFormat, Opts};
+use wit_bindgen_core::{Files, wit_parser::Resolve};
+
+fn generate_assembly() -> String {
+    const WIT: &str = r#"
+        package a:b;
+
+        world my-world {
+          import f: func(x: string) -> u32;
+        }
+    "#;
+
+    let mut resolve = Resolve::default();
+    let package = resolve.push_str("test.wit", WIT).unwrap();
+    let world = resolve.select_world(&[package], Some("my-world")).unwrap();
+    let mut files = Files::default();
+    let mut generator = Opts {
+        component_type_format: ComponentTypeFormat::Assembly,
+        ..Opts::default()
+    }
+    .build();
+    generator.generate(&mut resolve, world, &mut files).unwrap();
+
+    String::from_utf8(files.remove("my_world_component_type.s").unwrap()).unwrap()
+}
+
+#[test]
+fn assembly_component_type_quotes_section_name() {
+    let asm = generate_assembly();
+    // The `:` in the section name must be quoted for the directive to parse.
+    assert!(asm.contains("\t.section\t\".custom_section.component-type:my-world\",\"\",@\n"));
+}
+
+#[test]
+#[ignore = "requires `llvm-mc`, set `LLVM_MC` to override its path"]
+fn assembly_component_type_assembles() {
+    let asm = generate_assembly();
+
+    let llvm_mc = std::env::var("LLVM_MC").unwrap_or_else(|_| "llvm-mc".to_string());
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("component_type.s");
+    let output = dir.path().join("component_type.o");
+    std::fs::write(&input, &asm).unwrap();
+    let result = Command::new(&llvm_mc)
+        .arg("-triple=wasm32-unknown-unknown")
+        .arg("-filetype=obj")
+        .arg(&input)
+        .arg("-o")
+        .arg(&output)
+        .output()
+        .unwrap_or_else(|e| panic!("failed to run `{llvm_mc}`: {e}"));
+    assert!(
+        result.status.success(),
+        "`{llvm_mc}` failed:\n{}",
+        String::from_utf8_lossy(&result.stderr),
+    );
+
+    let object = std::fs::read(&output).unwrap();
+    let mut found = false;
+    for payload in wasmparser::Parser::new(0).parse_all(&object) {
+        if let wasmparser::Payload::CustomSection(section) = payload.unwrap() {
+            if section.name() == "component-type:my-world" {
+                assert!(section.data().starts_with(b"\0asm"));
+                found = true;
+            }
+        }
+    }
+    assert!(
+        found,
+        "component type section missing from assembled object"
+    );
+}
@@ -348,20 +348,26 @@ def_instruction! {
         } : [1] => [*size as usize],
 
         /// Pops an array and an address off the stack, passes each element to a block storing it
+        ///
+        /// The list starts `offset` bytes past the address, and the block
+        /// applies the same offset to each element's base pointer.
         FixedLengthListLowerToMemory {
             element: &'a Type,
             size: u32,
             id: TypeId,
+            offset: ArchitectureSize,
         } : [2] => [0],
 
         /// Pops base address, pushes an array
         ///
         /// This will also pop a block from the block stack which is how to
-        /// read each individual element from the list.
+        /// read each individual element from the list. As when lowering, the
+        /// list starts `offset` bytes past the address.
         FixedLengthListLiftFromMemory {
             element: &'a Type,
             size: u32,
             id: TypeId,
+            offset: ArchitectureSize,
         } : [1] => [1],
 
 
@@ -2081,6 +2087,7 @@ impl<'a, B: Bindgen> Generator<'a, B> {
                         element,
                         size: *size,
                         id,
+                        offset,
                     });
                 }
             },
@@ -2282,6 +2289,7 @@ impl<'a, B: Bindgen> Generator<'a, B> {
                         element: ty,
                         size: *size,
                         id,
+                        offset,
                     });
                 }
             },
@@ -1,6 +1,9 @@
 #pragma once
 
 #include <assert.h>
+#include <coroutine>
+#include <exception> // terminate
+#include <functional>
 #include <map>
 #include <optional>
 #include <stddef.h> // size_t
@@ -10,42 +13,111 @@
 #include <string>
 #include <string_view>
 #include <string.h> // memcpy
+#include <type_traits>
 #include <stdlib.h> // free
 #include <new>
 #include <span>
-#include <utility> // pair
+#include <utility> // pair, exchange
+#include <vector>
 
 namespace wit {
-/// @brief Helper class to map between IDs and resources
+/// @brief Generational slab mapping IDs to resources
+///
+/// An ID packs the slot index into its low bits and the slot's generation
+/// above them. Removing a resource bumps the generation of its slot, so a
+/// stale ID no longer matches once the slot is reused.
+///
+/// The table is not synchronized and must only be used from a single
+/// thread: like the rest of the bindings it assumes one thread per
+/// component instance.
 /// @tparam R Type of the Resource
 template <class R> class ResourceTable {
-  static std::map<int32_t, R> resources;
+  static constexpr unsigned index_bits = 20;
+  static constexpr uint32_t index_mask = (uint32_t(1) << index_bits) - 1;
+  // keeps IDs positive
+  static constexpr uint32_t generation_mask = 0x7ff;
+  struct Slot {
+    uint32_t generation = 1;
+    uint32_t next_free = index_mask;
+    std::optional<R> value;
+  };
+  static inline std::vector<Slot> slots;
+  static inline uint32_t free_head = index_mask;
+
+  static Slot *find(int32_t id) {
+    uint32_t index = uint32_t(id) & index_mask;
+    if (id < 0 || index >= slots.size()) {
+      return nullptr;
+    }
+    Slot &slot = slots[index];
+    if (!slot.value.has_value() ||
+        slot.generation != (uint32_t(id) >> index_bits)) {
+      return nullptr;
+    }
+    return &slot;
+  }
 
 public:
   static R *lookup_resource(int32_t id) {
-    auto result = resources.find(id);
-    return result == resources.end() ? nullptr : &result->second;
+    Slot *slot = find(id);
+    return slot ? &*slot->value : nullptr;
+  }
+  /// @brief Unchecked lookup, asserts that the ID is live in debug builds
+  static R &get_resource(int32_t id) {
+    assert(find(id) != nullptr && "resource used after drop");
+    return *slots[uint32_t(id) & index_mask].value;
   }
   static int32_t store_resource(R &&value) {
-    auto last = resources.rbegin();
-    int32_t id = last == resources.rend() ? 0 : last->first + 1;
-    resources.insert(std::pair<int32_t, R>(id, std::move(value)));
-    return id;
+    uint32_t index = free_head;
+    if (index != index_mask) {
+      free_head = slots[index].next_free;
+    } else {
+      index = uint32_t(slots.size());
+      if (index == index_mask) {
+        abort();
+      }
+      slots.emplace_back();
+    }
+    Slot &slot = slots[index];
+    slot.value.emplace(std::move(value));
+    return int32_t((slot.generation << index_bits) | index);
   }
   static std::optional<R> remove_resource(int32_t id) {
-    auto iter = resources.find(id);
+    Slot *slot = find(id);
     std::optional<R> result;
-    if (iter != resources.end()) {
-      result = std::move(iter->second);
-      resources.erase(iter);
+    if (slot) {
+      result = std::move(slot->value);
+      slot->value.reset();
+      slot->generation = (slot->generation + 1) & generation_mask;
+      if (slot->generation == 0) {
+        slot->generation = 1;
+      }
+      slot->next_free = free_head;
+      free_head = uint32_t(id) & index_mask;
     }
-    return std::move(result);
+    return result;
   }
 };
 
 /// @brief Replaces void in the error position of a result
 struct Void {};
 
+/// @brief Carries the `err` case of a `result` as an exception
+///
+/// Thrown by imports and caught by exports when the bindings are generated
+/// with `--error-style exceptions`.
+/// @tparam E Type of the error payload
+template <class E = Void> class error : public std::exception {
+  E value_;
+
+public:
+  explicit error(E value) : value_(std::move(value)) {}
+  E const &value() const & { return value_; }
+  E &value() & { return value_; }
+  E &&value() && { return std::move(value_); }
+  const char *what() const noexcept override { return "wit::error"; }
+};
+
 /// A string in linear memory, freed unconditionally using free
 ///
 /// A normal C++ string makes no guarantees about where the characters
@@ -171,6 +243,31 @@ public:
   } 
 };
 
+namespace detail {
+/// @brief Owns the buffers an import call lowers `std::vector` arguments
+/// into, they are freed once the call returned
+class ArgumentBuffers {
+  std::vector<void *> buffers;
+
+public:
+  ArgumentBuffers() = default;
+  ArgumentBuffers(ArgumentBuffers const &) = delete;
+  ArgumentBuffers &operator=(ArgumentBuffers const &) = delete;
+  ~ArgumentBuffers() {
+    for (auto buffer : buffers) {
+      free(buffer);
+    }
+  }
+  uint8_t *allocate(size_t size) {
+    if (!size) return nullptr;
+    void *buffer = malloc(size);
+    if (!buffer) abort();
+    buffers.push_back(buffer);
+    return (uint8_t *)buffer;
+  }
+};
+} // namespace detail
+
 /// @brief A map stored as a contiguous array of key-value pairs in linear
 /// memory, freed unconditionally using free.
 ///
@@ -331,4 +428,887 @@ public:
   }
   ResourceImportBase &operator=(ResourceImportBase const &r) = delete;
 };
+
+/// @brief State of a completed future or stream operation
+enum class WaitableState : uint8_t {
+  kCompleted = 0,
+  kDropped = 1,
+  kCancelled = 2,
+};
+
+/// @brief Result of a future or stream read or write
+///
+/// Wraps the packed `waitable-status` of the canonical ABI: the low four
+/// bits contain the state, the remaining bits the number of items copied.
+class WaitableStatus {
+  uint32_t status;
+
+public:
+  static constexpr uint32_t kBlocked = 0xffffffff;
+
+  explicit WaitableStatus(uint32_t s) : status(s) {}
+  bool is_blocked() const { return status == kBlocked; }
+  WaitableState state() const { return WaitableState(status & 0xf); }
+  uint32_t count() const { return status >> 4; }
+  uint32_t raw() const { return status; }
+};
+
+/// @brief Event codes delivered by `waitable-set.wait`
+enum class EventCode : uint32_t {
+  kNone = 0,
+  kSubtask = 1,
+  kStreamRead = 2,
+  kStreamWrite = 3,
+  kFutureRead = 4,
+  kFutureWrite = 5,
+  kCancel = 6,
+};
+
+namespace detail {
+extern "C" {
+__attribute__((__import_module__("$root"), __import_name__("[waitable-set-new]")))
+uint32_t wit_waitable_set_new(void);
+__attribute__((__import_module__("$root"), __import_name__("[waitable-join]")))
+void wit_waitable_join(uint32_t waitable, uint32_t set);
+__attribute__((__import_module__("$root"), __import_name__("[waitable-set-wait]")))
+uint32_t wit_waitable_set_wait(uint32_t set, uint32_t *payload);
+__attribute__((__import_module__("$root"), __import_name__("[waitable-set-drop]")))
+void wit_waitable_set_drop(uint32_t set);
+#ifdef WIT_ASYNC_SUBTASK_CANCEL
+// doesn't wait for the callee to acknowledge, needs the async builtins
+__attribute__((__import_module__("$root"), __import_name__("[async-lower][subtask-cancel]")))
+#else
+__attribute__((__import_module__("$root"), __import_name__("[subtask-cancel]")))
+#endif
+uint32_t wit_subtask_cancel(uint32_t subtask);
+__attribute__((__import_module__("$root"), __import_name__("[subtask-drop]")))
+void wit_subtask_drop(uint32_t subtask);
+__attribute__((__import_module__("$root"), __import_name__("[context-get-0]")))
+void *wit_context_get_0(void);
+__attribute__((__import_module__("$root"), __import_name__("[context-set-0]")))
+void wit_context_set_0(void *value);
+__attribute__((__import_module__("[export]$root"), __import_name__("[task-cancel]")))
+void wit_task_cancel(void);
+__attribute__((__import_module__("$root"), __import_name__("[backpressure-inc]")))
+void wit_backpressure_inc(void);
+__attribute__((__import_module__("$root"), __import_name__("[backpressure-dec]")))
+void wit_backpressure_dec(void);
+}
+
+/// @brief An operation waiting for an event on its waitable
+struct PendingOperation {
+  std::function<void(uint32_t)> callback;
+  /// Requests the cancellation of the operation, returns its status
+  std::function<uint32_t()> cancel;
+};
+
+/// @brief The operations pending within one task
+struct TaskState {
+  uint32_t set = 0;
+  std::map<uint32_t, PendingOperation> pending;
+  /// The coroutine of an export task, valid until `done` is set
+  std::coroutine_handle<> coroutine;
+  bool done = false;
+  bool cancelled = false;
+};
+} // namespace detail
+
+/// @brief Makes the host hold back new calls to this component's async
+/// exports, they stay in the starting state until a matching
+/// `backpressure_dec`
+inline void backpressure_inc() { detail::wit_backpressure_inc(); }
+/// @brief Undoes a `backpressure_inc`
+inline void backpressure_dec() { detail::wit_backpressure_dec(); }
+
+/// @brief Drives pending subtasks, future and stream operations to
+/// completion
+///
+/// Every operation which reports `BLOCKED` joins the waitable set of the
+/// current task together with the callback to invoke once the operation
+/// completes. Events are either waited for here or delivered by the host
+/// through `dispatch`. Each async export runs as its own task, everything
+/// else shares a single task state.
+class EventLoop {
+  static inline detail::TaskState main_state;
+  static inline detail::TaskState *current = &main_state;
+
+public:
+  /// Makes `state` the current task, returns the previous one
+  static detail::TaskState *enter(detail::TaskState *state) {
+    detail::TaskState *previous = current;
+    current = state;
+    return previous;
+  }
+  /// Returns the waitable set of the current task
+  static uint32_t waitable_set() {
+    if (!current->set) {
+      current->set = detail::wit_waitable_set_new();
+    }
+    return current->set;
+  }
+  /// Calls `callback` with the status `code` once the operation on
+  /// `waitable` has completed, immediately unless `code` is `BLOCKED`
+  ///
+  /// `cancel` is used to cancel the operation if the task is cancelled.
+  static void when_complete(uint32_t waitable, uint32_t code,
+                            std::function<void(uint32_t)> callback,
+                            std::function<uint32_t()> cancel = nullptr) {
+    if (code != WaitableStatus::kBlocked) {
+      callback(code);
+      return;
+    }
+    subscribe(waitable, std::move(callback), std::move(cancel));
+  }
+  /// Calls `callback` with the code of the next event on `waitable`
+  static void subscribe(uint32_t waitable,
+                        std::function<void(uint32_t)> callback,
+                        std::function<uint32_t()> cancel = nullptr) {
+    detail::wit_waitable_join(waitable, waitable_set());
+    current->pending[waitable] = {std::move(callback), std::move(cancel)};
+  }
+  /// Stops waiting for events on `waitable`
+  static void unsubscribe(uint32_t waitable) {
+    if (current->pending.erase(waitable)) {
+      detail::wit_waitable_join(waitable, 0);
+    }
+  }
+  static bool is_pending(uint32_t waitable) {
+    return current->pending.find(waitable) != current->pending.end();
+  }
+  static bool empty() { return current->pending.empty(); }
+  /// Finishes the operation pending on `waitable` with status `code`
+  static bool complete(uint32_t waitable, uint32_t code) {
+    auto iter = current->pending.find(waitable);
+    if (iter == current->pending.end()) {
+      return false;
+    }
+    auto callback = std::move(iter->second.callback);
+    current->pending.erase(iter);
+    callback(code);
+    return true;
+  }
+  /// Cancels the current export task
+  ///
+  /// The coroutine is destroyed at the point where it is suspended, which
+  /// cancels the subtasks and stream operations it awaits just like
+  /// destroying an unfinished `task`. Future and stream operations still
+  /// pending afterwards are cancelled as well, their callbacks observe
+  /// `WaitableState::kCancelled`. The task acknowledges the cancellation
+  /// with `task.cancel` once nothing is pending anymore.
+  static bool cancel() {
+    if (current->done || !current->coroutine) {
+      return false;
+    }
+    current->done = true;
+    current->cancelled = true;
+    std::exchange(current->coroutine, nullptr).destroy();
+    std::vector<uint32_t> waitables;
+    for (auto const &[waitable, operation] : current->pending) {
+      if (operation.cancel) {
+        waitables.push_back(waitable);
+      }
+    }
+    for (uint32_t waitable : waitables) {
+      auto iter = current->pending.find(waitable);
+      if (iter == current->pending.end() || !iter->second.cancel) {
+        continue;
+      }
+      uint32_t code = iter->second.cancel();
+      if (code != WaitableStatus::kBlocked) {
+        complete(waitable, code);
+      }
+    }
+    return true;
+  }
+  /// Handles an event, returns false if it didn't belong to an operation
+  static bool dispatch(uint32_t event, uint32_t waitable, uint32_t code) {
+    switch (EventCode(event)) {
+    case EventCode::kSubtask:
+    case EventCode::kStreamRead:
+    case EventCode::kStreamWrite:
+    case EventCode::kFutureRead:
+    case EventCode::kFutureWrite:
+      return complete(waitable, code);
+    case EventCode::kCancel:
+      return cancel();
+    default:
+      return false;
+    }
+  }
+  /// Blocks until the next event arrived and handles it,
+  /// returns false if no operation is pending
+  static bool wait_one() {
+    if (current->pending.empty()) {
+      return false;
+    }
+    uint32_t payload[2];
+    uint32_t event = detail::wit_waitable_set_wait(waitable_set(), payload);
+    dispatch(event, payload[0], payload[1]);
+    return true;
+  }
+  /// Blocks until all pending operations have completed
+  static void run() {
+    while (wait_one()) {
+    }
+  }
+};
+
+template <class T = void> class task;
+
+namespace detail {
+/// @brief Promise parts shared by all `task<T>`
+struct TaskPromiseBase {
+  std::coroutine_handle<> continuation;
+
+  struct FinalAwaiter {
+    bool await_ready() noexcept { return false; }
+    template <class P>
+    std::coroutine_handle<> await_suspend(std::coroutine_handle<P> h) noexcept {
+      auto continuation = h.promise().continuation;
+      return continuation ? continuation : std::noop_coroutine();
+    }
+    void await_resume() noexcept {}
+  };
+
+  std::suspend_always initial_suspend() noexcept { return {}; }
+  FinalAwaiter final_suspend() noexcept { return {}; }
+  void unhandled_exception() { std::terminate(); }
+};
+
+template <class T> struct TaskPromise : TaskPromiseBase {
+  std::optional<T> value;
+  task<T> get_return_object();
+  void return_value(T v) { value.emplace(std::move(v)); }
+  T result() { return std::move(*value); }
+};
+
+template <> struct TaskPromise<void> : TaskPromiseBase {
+  task<void> get_return_object();
+  void return_void() {}
+  void result() {}
+};
+} // namespace detail
+
+/// @brief A lazily started coroutine producing a `T`
+///
+/// Async imports return a `task` which starts the call once it is awaited
+/// or `start`ed, async exports are implemented as coroutines returning a
+/// `task`.
+template <class T> class task {
+public:
+  using promise_type = detail::TaskPromise<T>;
+
+private:
+  std::coroutine_handle<promise_type> handle;
+  bool started = false;
+
+public:
+  explicit task(std::coroutine_handle<promise_type> h) : handle(h) {}
+  task(task const &) = delete;
+  task(task &&b) : handle(b.handle), started(b.started) { b.handle = nullptr; }
+  task &operator=(task const &) = delete;
+  task &operator=(task &&b) {
+    if (handle) {
+      handle.destroy();
+    }
+    handle = b.handle;
+    started = b.started;
+    b.handle = nullptr;
+    return *this;
+  }
+  /// Destroying an unfinished task cancels the operations it awaits
+  ~task() {
+    if (handle) {
+      handle.destroy();
+    }
+  }
+  /// Runs the task until it first suspends without waiting for it, so
+  /// that it makes progress concurrently with the caller
+  void start() {
+    if (!started) {
+      started = true;
+      handle.resume();
+    }
+  }
+  /// Whether the task finished, its result can be awaited without
+  /// suspending
+  bool done() const { return handle.done(); }
+  bool await_ready() const noexcept { return started && handle.done(); }
+  std::coroutine_handle<> await_suspend(std::coroutine_handle<> h) noexcept {
+    handle.promise().continuation = h;
+    if (started) {
+      return std::noop_coroutine();
+    }
+    started = true;
+    return handle;
+  }
+  T await_resume() { return handle.promise().result(); }
+};
+
+namespace detail {
+template <class T> task<T> TaskPromise<T>::get_return_object() {
+  return task<T>(std::coroutine_handle<TaskPromise<T>>::from_promise(*this));
+}
+inline task<void> TaskPromise<void>::get_return_object() {
+  return task<void>(std::coroutine_handle<TaskPromise<void>>::from_promise(*this));
+}
+
+/// @brief Suspends a coroutine until an operation reports its result
+/// through a callback, the callback may be invoked right away
+template <class R> class CallbackAwaiter {
+  struct State {
+    std::optional<R> result;
+    std::coroutine_handle<> waiting;
+    bool alive = true;
+  };
+  std::function<void(std::function<void(R)>)> start;
+  std::shared_ptr<State> state;
+
+public:
+  explicit CallbackAwaiter(std::function<void(std::function<void(R)>)> s)
+      : start(std::move(s)), state(std::make_shared<State>()) {}
+  CallbackAwaiter(CallbackAwaiter &&) = default;
+  ~CallbackAwaiter() {
+    if (state) {
+      state->alive = false;
+    }
+  }
+  bool await_ready() const noexcept { return false; }
+  bool await_suspend(std::coroutine_handle<> h) {
+    start([state = state](R r) {
+      state->result.emplace(std::move(r));
+      if (state->waiting && state->alive) {
+        state->waiting.resume();
+      }
+    });
+    if (state->result) {
+      return false;
+    }
+    state->waiting = h;
+    return true;
+  }
+  R await_resume() { return std::move(*state->result); }
+};
+
+enum class SubtaskState : uint32_t {
+  kStarting = 0,
+  kStarted = 1,
+  kReturned = 2,
+  kStartedCancelled = 3,
+  kReturnedCancelled = 4,
+};
+
+/// Allocates the lowered arguments and results of an async import, `new[]`
+/// aligns them for any canonical ABI type
+inline std::shared_ptr<uint8_t[]> subtask_buffer(size_t size) {
+  return std::shared_ptr<uint8_t[]>(new uint8_t[size]);
+}
+
+/// @brief Awaits the subtask started by an async import
+///
+/// `status` is the packed result of the `[async-lower]` call. Destroying
+/// the awaiter before the subtask returned cancels it, `release` then
+/// frees the lowered arguments: it receives whether the subtask started,
+/// otherwise the arguments' owned handles are still ours to drop. With
+/// `WIT_ASYNC_SUBTASK_CANCEL` the cancellation doesn't block, if the callee
+/// doesn't acknowledge it right away the subtask stays pending in the
+/// event loop until it does. `release` and `buffer`, which holds the
+/// lowered arguments and results, are kept until then.
+class Subtask {
+  uint32_t status;
+  uint32_t handle = 0;
+  std::function<void(bool)> release;
+  std::shared_ptr<uint8_t[]> buffer;
+
+  void wait(std::coroutine_handle<> h) {
+    EventLoop::subscribe(handle, [this, h](uint32_t code) {
+      if (SubtaskState(code) == SubtaskState::kReturned) {
+        wit_subtask_drop(handle);
+        handle = 0;
+        h.resume();
+      } else {
+        wait(h);
+      }
+    });
+  }
+
+  /// Drops a cancelled subtask once it reached its final state `code`
+  static void finish_cancel(uint32_t handle, uint32_t code,
+                            std::function<void(bool)> release,
+                            std::shared_ptr<uint8_t[]> buffer) {
+    if (code == WaitableStatus::kBlocked ||
+        SubtaskState(code) == SubtaskState::kStarting ||
+        SubtaskState(code) == SubtaskState::kStarted) {
+      EventLoop::subscribe(handle, [handle, release = std::move(release),
+                                    buffer = std::move(buffer)](
+                                       uint32_t next) mutable {
+        finish_cancel(handle, next, std::move(release), std::move(buffer));
+      });
+      return;
+    }
+    wit_subtask_drop(handle);
+    if (release) {
+      release(SubtaskState(code) != SubtaskState::kStartedCancelled);
+    }
+  }
+
+public:
+  explicit Subtask(uint32_t s, std::function<void(bool)> r = nullptr,
+                   std::shared_ptr<uint8_t[]> b = nullptr)
+      : status(s), release(std::move(r)), buffer(std::move(b)) {}
+  Subtask(Subtask const &) = delete;
+  Subtask &operator=(Subtask const &) = delete;
+  ~Subtask() {
+    if (handle) {
+      EventLoop::unsubscribe(handle);
+      finish_cancel(handle, wit_subtask_cancel(handle), std::move(release),
+                    std::move(buffer));
+    }
+  }
+  bool await_ready() const noexcept {
+    return SubtaskState(status & 0xf) == SubtaskState::kReturned;
+  }
+  void await_suspend(std::coroutine_handle<> h) {
+    handle = status >> 4;
+    wait(h);
+  }
+  void await_resume() {}
+};
+
+/// @brief A coroutine which runs to completion on its own
+///
+/// `handle` is only valid while the coroutine is suspended.
+struct Detached {
+  struct promise_type {
+    Detached get_return_object() {
+      return {std::coroutine_handle<promise_type>::from_promise(*this)};
+    }
+    std::suspend_never initial_suspend() noexcept { return {}; }
+    std::suspend_never final_suspend() noexcept { return {}; }
+    void return_void() {}
+    void unhandled_exception() { std::terminate(); }
+  };
+  std::coroutine_handle<promise_type> handle;
+};
+
+/// Awaits `t`, passes its result to `f` and finally sets `*done`
+template <class T, class F> Detached run_detached(task<T> t, F f, bool *done) {
+  if constexpr (std::is_void_v<T>) {
+    co_await t;
+    f();
+  } else {
+    f(co_await t);
+  }
+  *done = true;
+}
+
+enum class CallbackCode : uint32_t {
+  kExit = 0,
+  kYield = 1,
+  kWait = 2,
+};
+
+/// Tells the host how to continue the export task owning `state`
+inline uint32_t callback_code(TaskState *state) {
+  if (state->done && state->pending.empty()) {
+    if (state->set) {
+      wit_waitable_set_drop(state->set);
+    }
+    if (state->cancelled) {
+      wit_task_cancel();
+    }
+    delete state;
+    wit_context_set_0(nullptr);
+    return uint32_t(CallbackCode::kExit);
+  }
+  wit_context_set_0(state);
+  if (state->pending.empty()) {
+    return uint32_t(CallbackCode::kYield);
+  }
+  return uint32_t(CallbackCode::kWait) | (state->set << 4);
+}
+
+/// Runs the coroutine of an async export until it first suspends,
+/// `task_return` receives its result
+template <class T, class F> uint32_t start_export(task<T> t, F task_return) {
+  TaskState *state = new TaskState();
+  TaskState *previous = EventLoop::enter(state);
+  Detached coroutine =
+      run_detached(std::move(t), std::move(task_return), &state->done);
+  if (!state->done) {
+    state->coroutine = coroutine.handle;
+  }
+  EventLoop::enter(previous);
+  return callback_code(state);
+}
+
+/// Delivers an event to the export task it belongs to, see
+/// `EventLoop::cancel` for how cancellation requests are handled
+inline uint32_t export_callback(uint32_t event, uint32_t waitable,
+                                uint32_t code) {
+  TaskState *state = (TaskState *)wit_context_get_0();
+  TaskState *previous = EventLoop::enter(state);
+  EventLoop::dispatch(event, waitable, code);
+  EventLoop::enter(previous);
+  return callback_code(state);
+}
+} // namespace detail
+
+/// @brief Runs `t` to completion, blocking on the waitable set of the
+/// current task, for use outside of coroutines
+template <class T> T block_on(task<T> t) {
+  bool done = false;
+  if constexpr (std::is_void_v<T>) {
+    detail::run_detached(std::move(t), [] {}, &done);
+    while (!done && EventLoop::wait_one()) {
+    }
+    // nothing is pending anymore, the task can't make progress
+    if (!done) {
+      abort();
+    }
+  } else {
+    std::optional<T> result;
+    detail::run_detached(
+        std::move(t), [&result](T v) { result.emplace(std::move(v)); }, &done);
+    while (!done && EventLoop::wait_one()) {
+    }
+    // nothing is pending anymore, the task can't make progress
+    if (!done) {
+      abort();
+    }
+    return std::move(*result);
+  }
+}
+
+/// @brief Canonical ABI operations of a `future<T>`, provided by the
+/// bindings for each payload type
+template <class T> struct FutureVtable {
+  size_t size;
+  uint64_t (*new_)(void);
+  uint32_t (*start_read)(uint32_t, uint8_t *);
+  uint32_t (*start_write)(uint32_t, uint8_t const *);
+  uint32_t (*cancel_read)(uint32_t);
+  uint32_t (*cancel_write)(uint32_t);
+  void (*drop_readable)(uint32_t);
+  void (*drop_writable)(uint32_t);
+  T (*lift)(uint8_t *);
+  void (*lower)(T &&, uint8_t *);
+  void (*dealloc_lists)(uint8_t *);
+};
+
+/// @brief Canonical ABI operations of a `stream<T>`, provided by the
+/// bindings for each payload type
+template <class T> struct StreamVtable {
+  size_t size;
+  uint64_t (*new_)(void);
+  uint32_t (*start_read)(uint32_t, uint8_t *, size_t);
+  uint32_t (*start_write)(uint32_t, uint8_t const *, size_t);
+  uint32_t (*cancel_read)(uint32_t);
+  uint32_t (*cancel_write)(uint32_t);
+  void (*drop_readable)(uint32_t);
+  void (*drop_writable)(uint32_t);
+  T (*lift)(uint8_t *);
+  void (*lower)(T &&, uint8_t *);
+  void (*dealloc_lists)(uint8_t *);
+};
+
+// specialized in the generated bindings
+template <class T> FutureVtable<T> const &future_vtable();
+template <class T> StreamVtable<T> const &stream_vtable();
+
+namespace detail {
+inline std::shared_ptr<uint8_t> allocate_buffer(size_t size) {
+  return std::shared_ptr<uint8_t>((uint8_t *)malloc(size ? size : 1), free);
+}
+} // namespace detail
+
+/// @brief The readable end of a `future<T>`, `T` is `wit::Void` for
+/// futures without a payload
+template <class T> class FutureReader {
+  uint32_t handle;
+
+public:
+  explicit FutureReader(uint32_t h = 0) : handle(h) {}
+  FutureReader(FutureReader const &) = delete;
+  FutureReader(FutureReader &&b) : handle(b.handle) { b.handle = 0; }
+  FutureReader &operator=(FutureReader const &) = delete;
+  FutureReader &operator=(FutureReader &&b) {
+    reset();
+    handle = b.handle;
+    b.handle = 0;
+    return *this;
+  }
+  ~FutureReader() { reset(); }
+  uint32_t get_handle() const { return handle; }
+  uint32_t into_handle() {
+    uint32_t h = handle;
+    handle = 0;
+    return h;
+  }
+  void reset() {
+    if (handle) {
+      future_vtable<T>().drop_readable(into_handle());
+    }
+  }
+  /// Reads the value, consuming the reader
+  ///
+  /// `callback` receives the value once it is available, or `std::nullopt`
+  /// if the read was cancelled.
+  void read(std::function<void(std::optional<T>)> callback) && {
+    auto buffer = detail::allocate_buffer(future_vtable<T>().size);
+    uint32_t h = into_handle();
+    uint32_t code = future_vtable<T>().start_read(h, buffer.get());
+    EventLoop::when_complete(
+        h, code,
+        [h, buffer, callback](uint32_t code) {
+          auto const &vtable = future_vtable<T>();
+          std::optional<T> result;
+          if (WaitableStatus(code).state() == WaitableState::kCompleted) {
+            result.emplace(vtable.lift(buffer.get()));
+          }
+          vtable.drop_readable(h);
+          callback(std::move(result));
+        },
+        [h] { return future_vtable<T>().cancel_read(h); });
+  }
+  /// Awaits the value, consuming the reader, see `read`
+  detail::CallbackAwaiter<std::optional<T>> operator co_await() && {
+    return detail::CallbackAwaiter<std::optional<T>>(
+        [h = into_handle()](auto callback) {
+          FutureReader<T>(h).read(std::move(callback));
+        });
+  }
+};
+
+/// @brief The writable end of a `future<T>`
+template <class T> class FutureWriter {
+  uint32_t handle;
+
+public:
+  explicit FutureWriter(uint32_t h = 0) : handle(h) {}
+  FutureWriter(FutureWriter const &) = delete;
+  FutureWriter(FutureWriter &&b) : handle(b.handle) { b.handle = 0; }
+  FutureWriter &operator=(FutureWriter const &) = delete;
+  FutureWriter &operator=(FutureWriter &&b) {
+    reset();
+    handle = b.handle;
+    b.handle = 0;
+    return *this;
+  }
+  ~FutureWriter() { reset(); }
+  uint32_t get_handle() const { return handle; }
+  uint32_t into_handle() {
+    uint32_t h = handle;
+    handle = 0;
+    return h;
+  }
+  void reset() {
+    if (handle) {
+      future_vtable<T>().drop_writable(into_handle());
+    }
+  }
+  /// Writes the value, consuming the writer
+  ///
+  /// `callback` receives whether the reader took the value; if it didn't
+  /// the value is destroyed.
+  void write(T value, std::function<void(bool)> callback = nullptr) && {
+    auto const &vtable = future_vtable<T>();
+    auto buffer = detail::allocate_buffer(vtable.size);
+    vtable.lower(std::move(value), buffer.get());
+    uint32_t h = into_handle();
+    uint32_t code = vtable.start_write(h, buffer.get());
+    EventLoop::when_complete(
+        h, code,
+        [h, buffer, callback](uint32_t code) {
+          auto const &vtable = future_vtable<T>();
+          bool written =
+              WaitableStatus(code).state() == WaitableState::kCompleted;
+          if (written) {
+            vtable.dealloc_lists(buffer.get());
+          } else {
+            // take back ownership to release it
+            vtable.lift(buffer.get());
+          }
+          vtable.drop_writable(h);
+          if (callback) {
+            callback(written);
+          }
+        },
+        [h] { return future_vtable<T>().cancel_write(h); });
+  }
+  /// Writes the value and awaits whether the reader took it, see `write`
+  [[nodiscard]] detail::CallbackAwaiter<bool> async_write(T value) && {
+    return detail::CallbackAwaiter<bool>(
+        [h = into_handle(),
+         value = std::make_shared<T>(std::move(value))](auto callback) {
+          FutureWriter<T>(h).write(std::move(*value), std::move(callback));
+        });
+  }
+};
+
+/// @brief Creates a new future, returns the writable and readable end
+template <class T> std::pair<FutureWriter<T>, FutureReader<T>> future_new() {
+  uint64_t packed = future_vtable<T>().new_();
+  return std::pair<FutureWriter<T>, FutureReader<T>>(
+      FutureWriter<T>(uint32_t(packed >> 32)), FutureReader<T>(uint32_t(packed)));
+}
+
+/// @brief The readable end of a `stream<T>`, `T` is `wit::Void` for
+/// streams without a payload
+///
+/// Destroying the reader cancels a pending read.
+template <class T> class StreamReader {
+  uint32_t handle;
+
+public:
+  explicit StreamReader(uint32_t h = 0) : handle(h) {}
+  StreamReader(StreamReader const &) = delete;
+  StreamReader(StreamReader &&b) : handle(b.handle) { b.handle = 0; }
+  StreamReader &operator=(StreamReader const &) = delete;
+  StreamReader &operator=(StreamReader &&b) {
+    reset();
+    handle = b.handle;
+    b.handle = 0;
+    return *this;
+  }
+  ~StreamReader() { reset(); }
+  uint32_t get_handle() const { return handle; }
+  uint32_t into_handle() {
+    uint32_t h = handle;
+    handle = 0;
+    return h;
+  }
+  void reset() {
+    if (handle) {
+      auto const &vtable = stream_vtable<T>();
+      if (EventLoop::is_pending(handle)) {
+        EventLoop::complete(handle, vtable.cancel_read(handle));
+      }
+      vtable.drop_readable(into_handle());
+    }
+  }
+  /// Reads up to `max` items
+  ///
+  /// `callback` receives the items read together with the state of the
+  /// read, `WaitableState::kDropped` signals the end of the stream.
+  void read(size_t max,
+            std::function<void(std::vector<T>, WaitableState)> callback) {
+    auto const &vtable = stream_vtable<T>();
+    auto buffer = detail::allocate_buffer(vtable.size * max);
+    uint32_t code = vtable.start_read(handle, buffer.get(), max);
+    EventLoop::when_complete(
+        handle, code,
+        [buffer, callback](uint32_t code) {
+          auto const &vtable = stream_vtable<T>();
+          WaitableStatus status(code);
+          std::vector<T> items;
+          items.reserve(status.count());
+          for (uint32_t i = 0; i < status.count(); ++i) {
+            items.push_back(vtable.lift(buffer.get() + i * vtable.size));
+          }
+          callback(std::move(items), status.state());
+        },
+        [h = handle] { return stream_vtable<T>().cancel_read(h); });
+  }
+  /// Reads up to `max` items and awaits them, see `read`
+  detail::CallbackAwaiter<std::pair<std::vector<T>, WaitableState>>
+  async_read(size_t max) {
+    using Result = std::pair<std::vector<T>, WaitableState>;
+    return detail::CallbackAwaiter<Result>([this, max](auto callback) {
+      read(max, [callback](std::vector<T> items, WaitableState state) {
+        callback(Result(std::move(items), state));
+      });
+    });
+  }
+};
+
+/// @brief The writable end of a `stream<T>`
+///
+/// Destroying the writer cancels a pending write.
+template <class T> class StreamWriter {
+  uint32_t handle;
+
+public:
+  explicit StreamWriter(uint32_t h = 0) : handle(h) {}
+  StreamWriter(StreamWriter const &) = delete;
+  StreamWriter(StreamWriter &&b) : handle(b.handle) { b.handle = 0; }
+  StreamWriter &operator=(StreamWriter const &) = delete;
+  StreamWriter &operator=(StreamWriter &&b) {
+    reset();
+    handle = b.handle;
+    b.handle = 0;
+    return *this;
+  }
+  ~StreamWriter() { reset(); }
+  uint32_t get_handle() const { return handle; }
+  uint32_t into_handle() {
+    uint32_t h = handle;
+    handle = 0;
+    return h;
+  }
+  void reset() {
+    if (handle) {
+      auto const &vtable = stream_vtable<T>();
+      if (EventLoop::is_pending(handle)) {
+        EventLoop::complete(handle, vtable.cancel_write(handle));
+      }
+      vtable.drop_writable(into_handle());
+    }
+  }
+  /// Writes `items`
+  ///
+  /// `callback` receives the items which weren't taken by the reader
+  /// together with the state of the write, `WaitableState::kDropped`
+  /// signals that the reader went away.
+  void write(std::vector<T> items,
+             std::function<void(std::vector<T>, WaitableState)> callback =
+                 nullptr) {
+    auto const &vtable = stream_vtable<T>();
+    size_t count = items.size();
+    auto buffer = detail::allocate_buffer(vtable.size * count);
+    for (size_t i = 0; i < count; ++i) {
+      vtable.lower(std::move(items[i]), buffer.get() + i * vtable.size);
+    }
+    uint32_t code = vtable.start_write(handle, buffer.get(), count);
+    EventLoop::when_complete(
+        handle, code, [buffer, count, callback](uint32_t code) {
+          auto const &vtable = stream_vtable<T>();
+          WaitableStatus status(code);
+          std::vector<T> remaining;
+          for (size_t i = 0; i < count; ++i) {
+            uint8_t *ptr = buffer.get() + i * vtable.size;
+            if (i < status.count()) {
+              vtable.dealloc_lists(ptr);
+            } else {
+              remaining.push_back(vtable.lift(ptr));
+            }
+          }
+          if (callback) {
+            callback(std::move(remaining), status.state());
+          }
+        },
+        [h = handle] { return stream_vtable<T>().cancel_write(h); });
+  }
+  /// Writes `items` and awaits the items not taken, see `write`
+  [[nodiscard]] detail::CallbackAwaiter<
+      std::pair<std::vector<T>, WaitableState>>
+  async_write(std::vector<T> items) {
+    using Result = std::pair<std::vector<T>, WaitableState>;
+    return detail::CallbackAwaiter<Result>(
+        [this, items = std::make_shared<std::vector<T>>(std::move(items))](
+            auto callback) {
+          write(std::move(*items),
+                [callback](std::vector<T> remaining, WaitableState state) {
+                  callback(Result(std::move(remaining), state));
+                });
+        });
+  }
+};
+
+/// @brief Creates a new stream, returns the writable and readable end
+template <class T> std::pair<StreamWriter<T>, StreamReader<T>> stream_new() {
+  uint64_t packed = stream_vtable<T>().new_();
+  return std::pair<StreamWriter<T>, StreamReader<T>>(
+      StreamWriter<T>(uint32_t(packed >> 32)), StreamReader<T>(uint32_t(packed)));
+}
 } // namespace wit
@@ -12,13 +12,13 @@ use std::{
 use symbol_name::{make_external_component, make_external_symbol};
 use wit_bindgen_c::to_c_ident;
 use wit_bindgen_core::{
-    Files, InterfaceGenerator, Source, Types, WorldGenerator,
+    AsyncFilterSet, Files, InterfaceGenerator, Source, Types, WorldGenerator,
     abi::{self, AbiVariant, Bindgen, Bitcast, LiftLower, WasmSignature, WasmType},
-    name_package_module, uwrite, uwriteln,
+    dealias, name_package_module, uwrite, uwriteln,
     wit_parser::{
-        Alignment, ArchitectureSize, Docs, Function, FunctionKind, Handle, Int, InterfaceId, Param,
-        Resolve, SizeAlign, Stability, Type, TypeDef, TypeDefKind, TypeId, TypeOwner, WorldId,
-        WorldKey,
+        Alignment, ArchitectureSize, Docs, Function, FunctionKind, Handle, Int, InterfaceId,
+        LiveTypes, Param, Resolve, SizeAlign, Stability, Type, TypeDef, TypeDefKind, TypeId,
+        TypeOwner, WorldId, WorldItem, WorldKey,
     },
 };
 use wit_parser::TypeIdVisitor;
@@ -77,6 +77,7 @@ struct Includes {
     needs_wit: bool,
     needs_memory: bool,
     needs_array: bool,
+    needs_unordered_map: bool,
 }
 
 #[derive(Default)]
@@ -111,6 +112,11 @@ struct Cpp {
     // needed for symmetric disambiguation
     interface_prefixes: HashMap<(Direction, WorldKey), String>,
     import_prefix: Option<String>,
+
+    // future and stream vtables, keyed by the C++ handle type
+    vtables: HashSet<String>,
+    vtables_h: Source,
+    vtables_c: Source,
 }
 
 #[cfg(feature = "clap")]
@@ -174,6 +180,53 @@ pub struct Opts {
     #[cfg_attr(feature = "clap", arg(long, default_value_t = Ownership::Owning))]
     pub ownership: Ownership,
 
+    /// Use `std::string`, `std::vector` and `std::unordered_map` for owned
+    /// strings, lists and maps instead of the `wit::` types from `wit.h`.
+    ///
+    /// The glue code copies between them and linear memory, imported
+    /// functions take lists as `std::span<T const>` and maps as
+    /// `std::unordered_map<K, V> const&`.
+    #[cfg_attr(feature = "clap", arg(long, default_value_t = bool::default()))]
+    pub std_types: bool,
+
+    /// How the `err` case of a `result` returned by a function is surfaced.
+    ///
+    /// Valid values include:
+    ///
+    /// - `expected`: Functions return `std::expected<T, E>`.
+    ///
+    /// - `exceptions`: Functions return `T`, imports throw `wit::error<E>`
+    ///   for the `err` case and exports turn a thrown `wit::error<E>` into
+    ///   it. Async functions and nested `result`s keep using `std::expected`.
+    #[cfg_attr(feature = "clap", arg(long, default_value_t = ErrorStyle::Expected))]
+    pub error_style: ErrorStyle,
+
+    /// Function template called as `HANDLER<E>(std::current_exception())`
+    /// when an export bound with `--error-style exceptions` throws anything
+    /// but `wit::error<E>`, the returned `E` becomes the `err` case.
+    ///
+    /// By default such exceptions propagate out of the export.
+    #[cfg_attr(feature = "clap", arg(long, value_name = "HANDLER"))]
+    pub error_fallback: Option<String>,
+
+    /// How exported resources are identified in the handles the host passes
+    /// back to the guest.
+    ///
+    /// Valid values include:
+    ///
+    /// - `pointer`: The resource's address is its representation.
+    ///
+    /// - `slab`: Resources are stored in a generational `wit::ResourceTable`
+    ///   and represented by their index in it. Builds without `NDEBUG`
+    ///   assert on handles used after their resource was dropped. The table
+    ///   isn't synchronized, it must only be used from a single thread.
+    #[cfg_attr(feature = "clap", arg(long, default_value_t = ResourceTableKind::Pointer))]
+    pub resource_table: ResourceTableKind,
+
+    /// Async functions are bound as C++20 coroutines returning `wit::task`.
+    #[cfg_attr(feature = "clap", clap(flatten))]
+    pub async_: AsyncFilterSet,
+
     /// Where to place output files
     #[cfg_attr(feature = "clap", arg(skip))]
     out_dir: Option<PathBuf>,
@@ -262,6 +315,74 @@ impl fmt::Display for Ownership {
     }
 }
 
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorStyle {
+    /// Functions returning `result<T, E>` return `std::expected<T, E>`.
+    #[default]
+    Expected,
+
+    /// Functions returning `result<T, E>` return `T` and the `err` case is
+    /// thrown as `wit::error<E>`.
+    Exceptions,
+}
+
+impl FromStr for ErrorStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "expected" => Ok(Self::Expected),
+            "exceptions" => Ok(Self::Exceptions),
+            _ => Err(format!(
+                "unrecognized error style: `{s}`; expected `expected` or `exceptions`"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ErrorStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ErrorStyle::Expected => "expected",
+            ErrorStyle::Exceptions => "exceptions",
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceTableKind {
+    /// Exported resources are represented by their address.
+    #[default]
+    Pointer,
+
+    /// Exported resources are represented by their index in a generational
+    /// slab.
+    Slab,
+}
+
+impl FromStr for ResourceTableKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pointer" => Ok(Self::Pointer),
+            "slab" => Ok(Self::Slab),
+            _ => Err(format!(
+                "unrecognized resource table: `{s}`; expected `pointer` or `slab`"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ResourceTableKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ResourceTableKind::Pointer => "pointer",
+            ResourceTableKind::Slab => "slab",
+        })
+    }
+}
+
 impl Opts {
     pub fn build(mut self, out_dir: Option<&PathBuf>) -> Box<dyn WorldGenerator> {
         let mut r = Cpp::new();
@@ -340,7 +461,7 @@ impl Cpp {
             r#gen: self,
             resolve,
             interface: None,
-            _name: name,
+            name,
             sizes,
             in_guest_import,
             wasm_import_module,
@@ -449,6 +570,9 @@ impl Cpp {
         if self.dependencies.needs_array {
             self.include("<array>");
         }
+        if self.dependencies.needs_unordered_map {
+            self.include("<unordered_map>");
+        }
         if self.dependencies.needs_bit {
             self.include("<bit>");
         }
@@ -498,12 +622,66 @@ struct FileContext {
     dependencies: Includes,
 }
 
+impl Cpp {
+    /// The C++ async lowering doesn't handle fixed-length lists in async
+    /// signatures yet, reject those functions with a clear error.
+    fn check_async_fixed_length_lists(
+        &mut self,
+        resolve: &Resolve,
+        world: WorldId,
+    ) -> anyhow::Result<()> {
+        let world = &resolve.worlds[world];
+        let items = world
+            .imports
+            .iter()
+            .map(|item| (item, true))
+            .chain(world.exports.iter().map(|item| (item, false)));
+        for ((key, item), is_import) in items {
+            let (interface, funcs) = match item {
+                WorldItem::Interface { id, .. } => (
+                    Some(key),
+                    resolve.interfaces[*id]
+                        .functions
+                        .values()
+                        .collect::<Vec<_>>(),
+                ),
+                WorldItem::Function(func) => (None, vec![func]),
+                WorldItem::Type { .. } => continue,
+            };
+            for func in funcs {
+                if matches!(func.kind, FunctionKind::Constructor(_))
+                    || !matches!(is_special_method(func), SpecialMethod::None)
+                    || !uses_fixed_length_list(resolve, func)
+                {
+                    continue;
+                }
+                if self
+                    .opts
+                    .async_
+                    .is_async(resolve, interface, func, is_import)
+                {
+                    bail!(
+                        "async function `{}` uses a fixed-length list, which the C++ \
+                         generator doesn't support for async functions yet",
+                        match interface {
+                            Some(key) => format!("{}#{}", resolve.name_world_key(key), func.name),
+                            None => func.name.clone(),
+                        }
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 impl WorldGenerator for Cpp {
     fn preprocess(&mut self, resolve: &Resolve, world: WorldId) -> anyhow::Result<()> {
         let name = &resolve.worlds[world].name;
         self.world = name.to_string();
         self.types.analyze(resolve);
         self.world_id = Some(world);
+        self.check_async_fixed_length_lists(resolve, world)?;
         uwriteln!(
             self.c_src_head,
             r#"#include "{}_cpp.h"
@@ -560,7 +738,10 @@ impl WorldGenerator for Cpp {
                 r#gen.types(id);
 
                 for (_name, func) in resolve.interfaces[id].functions.iter() {
-                    if matches!(func.kind, FunctionKind::Freestanding) {
+                    if matches!(
+                        func.kind,
+                        FunctionKind::Freestanding | FunctionKind::AsyncFreestanding
+                    ) {
                         r#gen.r#gen.h_src.change_namespace(&namespace);
                         r#gen.generate_function(
                             func,
@@ -616,7 +797,10 @@ impl WorldGenerator for Cpp {
         r#gen.types(id);
 
         for (_name, func) in resolve.interfaces[id].functions.iter() {
-            if matches!(func.kind, FunctionKind::Freestanding) {
+            if matches!(
+                func.kind,
+                FunctionKind::Freestanding | FunctionKind::AsyncFreestanding
+            ) {
                 r#gen.r#gen.h_src.change_namespace(&namespace);
                 r#gen.generate_function(func, &TypeOwner::Interface(id), AbiVariant::GuestExport);
             }
@@ -640,7 +824,10 @@ impl WorldGenerator for Cpp {
         let namespace = namespace(resolve, &TypeOwner::World(world), false, &*r#gen.r#gen);
 
         for (_name, func) in funcs.iter() {
-            if matches!(func.kind, FunctionKind::Freestanding) {
+            if matches!(
+                func.kind,
+                FunctionKind::Freestanding | FunctionKind::AsyncFreestanding
+            ) {
                 r#gen.r#gen.h_src.change_namespace(&namespace);
                 r#gen.generate_function(func, &TypeOwner::World(world), AbiVariant::GuestImport);
             }
@@ -660,7 +847,10 @@ impl WorldGenerator for Cpp {
         let namespace = namespace(resolve, &TypeOwner::World(world), true, &*r#gen.r#gen);
 
         for (_name, func) in funcs.iter() {
-            if matches!(func.kind, FunctionKind::Freestanding) {
+            if matches!(
+                func.kind,
+                FunctionKind::Freestanding | FunctionKind::AsyncFreestanding
+            ) {
                 r#gen.r#gen.h_src.change_namespace(&namespace);
                 r#gen.generate_function(func, &TypeOwner::World(world), AbiVariant::GuestExport);
             }
@@ -743,6 +933,14 @@ impl WorldGenerator for Cpp {
         c_str.src.push_str(&self.c_src.src);
         self.h_src.change_namespace(&Vec::default());
         h_str.src.push_str(&self.h_src.src);
+        if !self.vtables_h.is_empty() {
+            uwriteln!(h_str.src, "namespace wit {{");
+            h_str.src.push_str(&self.vtables_h);
+            uwriteln!(h_str.src, "}}");
+            uwriteln!(c_str.src, "namespace wit {{");
+            c_str.src.push_str(&self.vtables_c);
+            uwriteln!(c_str.src, "}}");
+        }
 
         uwriteln!(c_str.src, "\n// Component Adapters");
 
@@ -787,6 +985,7 @@ impl WorldGenerator for Cpp {
         if self.dependencies.needs_wit {
             files.push("wit.h", include_bytes!("../helper-types/wit.h"));
         }
+        self.opts.async_.ensure_all_used()?;
         Ok(())
     }
 }
@@ -891,7 +1090,7 @@ struct CppInterfaceGenerator<'a> {
     r#gen: &'a mut Cpp,
     resolve: &'a Resolve,
     interface: Option<InterfaceId>,
-    _name: Option<&'a WorldKey>,
+    name: Option<&'a WorldKey>,
     sizes: SizeAlign,
     in_guest_import: bool,
     pub wasm_import_module: Option<String>,
@@ -923,10 +1122,12 @@ impl CppInterfaceGenerator<'_> {
             TypeDefKind::Result(r) => self.type_result(id, name, r, &ty.docs),
             TypeDefKind::List(t) => self.type_list(id, name, t, &ty.docs),
             TypeDefKind::Type(t) => self.type_alias(id, name, t, &ty.docs),
-            TypeDefKind::Future(_) => todo!("generate for future"),
-            TypeDefKind::Stream(_) => todo!("generate for stream"),
+            TypeDefKind::Future(t) => self.type_future(id, name, t, &ty.docs),
+            TypeDefKind::Stream(t) => self.type_stream(id, name, t, &ty.docs),
             TypeDefKind::Handle(_) => todo!("generate for handle"),
-            TypeDefKind::FixedLengthList(_, _) => todo!(),
+            TypeDefKind::FixedLengthList(t, size) => {
+                self.type_fixed_length_list(id, name, t, *size, &ty.docs)
+            }
             TypeDefKind::Map(k, v) => self.type_map(id, name, k, v, &ty.docs),
             TypeDefKind::Unknown => unreachable!(),
         }
@@ -940,13 +1141,12 @@ impl CppInterfaceGenerator<'_> {
         cpp_file: bool,
     ) -> (Vec<String>, String) {
         let (object, owner) = match &func.kind {
-            FunctionKind::Freestanding => None,
-            FunctionKind::Method(i) => Some(i),
-            FunctionKind::Static(i) => Some(i),
-            FunctionKind::Constructor(i) => Some(i),
-            FunctionKind::AsyncFreestanding => todo!(),
-            FunctionKind::AsyncMethod(_id) => todo!(),
-            FunctionKind::AsyncStatic(_id) => todo!(),
+            FunctionKind::Freestanding | FunctionKind::AsyncFreestanding => None,
+            FunctionKind::Method(i)
+            | FunctionKind::AsyncMethod(i)
+            | FunctionKind::Static(i)
+            | FunctionKind::AsyncStatic(i)
+            | FunctionKind::Constructor(i) => Some(i),
         }
         .map(|i| {
             let ty = &self.resolve.types[*i];
@@ -960,7 +1160,10 @@ impl CppInterfaceGenerator<'_> {
         ));
         let mut namespace = namespace(self.resolve, &owner, guest_export, &*self.r#gen);
         let is_drop = is_special_method(func);
-        let func_name_h = if !matches!(&func.kind, FunctionKind::Freestanding) {
+        let func_name_h = if !matches!(
+            &func.kind,
+            FunctionKind::Freestanding | FunctionKind::AsyncFreestanding
+        ) {
             namespace.push(object.clone());
             if let FunctionKind::Constructor(_i) = &func.kind {
                 // Fallible constructors return result<T, E> and are static factory methods
@@ -997,7 +1200,12 @@ impl CppInterfaceGenerator<'_> {
     }
 
     // print the signature of the guest export (lowered (wasm) function calling into highlevel)
-    fn print_export_signature(&mut self, func: &Function, variant: AbiVariant) -> Vec<String> {
+    fn print_export_signature(
+        &mut self,
+        func: &Function,
+        variant: AbiVariant,
+        async_: bool,
+    ) -> Vec<String> {
         let is_drop = is_special_method(func);
         let id_type = WasmType::I32;
         let signature = match is_drop {
@@ -1025,6 +1233,9 @@ impl CppInterfaceGenerator<'_> {
                 indirect_params: false,
                 retptr: false,
             },
+            SpecialMethod::None if async_ => self
+                .resolve
+                .wasm_signature(AbiVariant::GuestExportAsync, func),
             SpecialMethod::None => {
                 // TODO perhaps remember better names for the arguments
                 self.resolve.wasm_signature(variant, func)
@@ -1054,9 +1265,10 @@ impl CppInterfaceGenerator<'_> {
             res.push('#');
             res
         });
+        let lift_prefix = if async_ { "[async-lift]" } else { "" };
         uwriteln!(
             self.r#gen.c_src.src,
-            r#"extern "C" __attribute__((__export_name__("{module_prefix}{func_name}")))"#
+            r#"extern "C" __attribute__((__export_name__("{lift_prefix}{module_prefix}{func_name}")))"#
         );
         let return_via_pointer = false;
         self.r#gen
@@ -1110,6 +1322,7 @@ impl CppInterfaceGenerator<'_> {
         func: &Function,
         abi_variant: AbiVariant,
         outer_namespace: &[String],
+        async_: bool,
     ) -> HighlevelSignature {
         let mut res = HighlevelSignature::default();
 
@@ -1131,6 +1344,24 @@ impl CppInterfaceGenerator<'_> {
         {
             if matches!(is_drop, SpecialMethod::Allocate) {
                 res.result.push_str("Owned");
+            } else if async_ {
+                let result = self.optional_type_name(
+                    func.result.as_ref(),
+                    outer_namespace,
+                    Flavor::InStruct,
+                );
+                self.r#gen.dependencies.needs_wit = true;
+                res.result = if result == "void" {
+                    "wit::task<>".into()
+                } else {
+                    format!("wit::task<{result}>")
+                };
+            } else if let Some(id) = self.thrown_result(func, async_) {
+                let TypeDefKind::Result(r) = &self.resolve.types[id].kind else {
+                    unreachable!()
+                };
+                res.result =
+                    self.optional_type_name(r.ok.as_ref(), outer_namespace, Flavor::InStruct);
             } else if let Some(ty) = &func.result {
                 res.result.push_str(
                     &(self.type_name(ty, outer_namespace, Flavor::Result(abi_variant))
@@ -1144,12 +1375,16 @@ impl CppInterfaceGenerator<'_> {
                 res.result = "void".into();
             }
             if matches!(abi_variant, AbiVariant::GuestExport)
+                && !async_
                 && abi::guest_export_needs_post_return(self.resolve, func)
             {
                 res.post_return = true;
             }
         }
-        if (matches!(func.kind, FunctionKind::Static(_)) || is_fallible_constructor)
+        if (matches!(
+            func.kind,
+            FunctionKind::Static(_) | FunctionKind::AsyncStatic(_)
+        ) || is_fallible_constructor)
             && !(matches!(&is_drop, SpecialMethod::ResourceDrop)
                 && matches!(abi_variant, AbiVariant::GuestImport))
         {
@@ -1164,9 +1399,11 @@ impl CppInterfaceGenerator<'_> {
         {
             if i == 0
                 && name == "self"
-                && (matches!(&func.kind, FunctionKind::Method(_))
-                    || (matches!(&is_drop, SpecialMethod::ResourceDrop)
-                        && matches!(abi_variant, AbiVariant::GuestImport)))
+                && (matches!(
+                    &func.kind,
+                    FunctionKind::Method(_) | FunctionKind::AsyncMethod(_)
+                ) || (matches!(&is_drop, SpecialMethod::ResourceDrop)
+                    && matches!(abi_variant, AbiVariant::GuestImport)))
             {
                 res.implicit_self = true;
                 continue;
@@ -1180,14 +1417,24 @@ impl CppInterfaceGenerator<'_> {
             } else {
                 ""
             };
+            // the coroutine outlives the caller's temporaries, so it owns its arguments
+            let flavor = if async_ {
+                Flavor::InStruct
+            } else {
+                Flavor::Argument(abi_variant)
+            };
             res.arguments.push((
                 to_c_ident(name),
-                self.type_name(param, &res.namespace, Flavor::Argument(abi_variant)) + is_pointer,
+                self.type_name(param, &res.namespace, flavor) + is_pointer,
             ));
         }
         // default to non-const when exporting a method
         let import = matches!(abi_variant, AbiVariant::GuestImport);
-        if matches!(func.kind, FunctionKind::Method(_)) && import {
+        if matches!(
+            func.kind,
+            FunctionKind::Method(_) | FunctionKind::AsyncMethod(_)
+        ) && import
+        {
             res.const_member = true;
         }
         res
@@ -1198,10 +1445,11 @@ impl CppInterfaceGenerator<'_> {
         func: &Function,
         variant: AbiVariant,
         import: bool,
+        async_: bool,
     ) -> Vec<String> {
         let is_special = is_special_method(func);
         let from_namespace = self.r#gen.h_src.namespace.clone();
-        let cpp_sig = self.high_level_signature(func, variant, &from_namespace);
+        let cpp_sig = self.high_level_signature(func, variant, &from_namespace, async_);
         if cpp_sig.static_member {
             self.r#gen.h_src.src.push_str("static ");
         }
@@ -1263,11 +1511,11 @@ impl CppInterfaceGenerator<'_> {
                     | SpecialMethod::ResourceRep
             )
         {
-            self.print_export_signature(func, variant)
+            self.print_export_signature(func, variant, async_)
         } else {
             // recalulate with c file namespace
             let c_namespace = self.r#gen.c_src.namespace.clone();
-            let cpp_sig = self.high_level_signature(func, variant, &c_namespace);
+            let cpp_sig = self.high_level_signature(func, variant, &c_namespace, async_);
             let mut params = Vec::new();
             self.r#gen.c_src.src.push_str(&cpp_sig.result);
             if !cpp_sig.result.is_empty() {
@@ -1328,7 +1576,8 @@ impl CppInterfaceGenerator<'_> {
             uwriteln!(self.r#gen.h_src.src, "");
         }
         Self::docs(&mut self.r#gen.h_src.src, &func.docs);
-        let params = self.print_signature(func, variant, !export);
+        let async_ = self.is_async(func, variant);
+        let params = self.print_signature(func, variant, !export, async_);
         let special = is_special_method(func);
         if !matches!(special, SpecialMethod::Allocate) {
             self.r#gen.c_src.src.push_str("{\n");
@@ -1376,8 +1625,18 @@ impl CppInterfaceGenerator<'_> {
                 },
                 SpecialMethod::Dtor => {
                     let classname = class_namespace(self, func, variant).join("::");
-                    uwriteln!(self.r#gen.c_src.src, "(({classname}*)arg0)->handle=-1;");
-                    uwriteln!(self.r#gen.c_src.src, "{0}::Dtor(({0}*)arg0);", classname);
+                    if self.r#gen.opts.resource_table == ResourceTableKind::Slab {
+                        uwriteln!(
+                            self.r#gen.c_src.src,
+                            "auto obj = wit::{RESOURCE_TABLE_NAME}<{classname}*>::remove_resource((int32_t)(uintptr_t)arg0);
+                            if (!obj) abort();
+                            (*obj)->handle=-1;
+                            {classname}::Dtor(*obj);"
+                        );
+                    } else {
+                        uwriteln!(self.r#gen.c_src.src, "(({classname}*)arg0)->handle=-1;");
+                        uwriteln!(self.r#gen.c_src.src, "{0}::Dtor(({0}*)arg0);", classname);
+                    }
                 }
                 SpecialMethod::ResourceNew => {
                     let module_name =
@@ -1388,12 +1647,21 @@ impl CppInterfaceGenerator<'_> {
                         &[WasmType::Pointer],
                         &[WasmType::I32],
                     );
-                    uwriteln!(
-                        self.r#gen.c_src.src,
-                        "return {wasm_sig}(({}){});",
-                        self.r#gen.opts.ptr_type(),
-                        func.params.first().unwrap().name
-                    );
+                    let param = &func.params.first().unwrap().name;
+                    if self.r#gen.opts.resource_table == ResourceTableKind::Slab {
+                        let classname = class_namespace(self, func, variant).join("::");
+                        uwriteln!(
+                            self.r#gen.c_src.src,
+                            "return {wasm_sig}(({})(uintptr_t)wit::{RESOURCE_TABLE_NAME}<{classname}*>::store_resource(std::move({param})));",
+                            self.r#gen.opts.ptr_type(),
+                        );
+                    } else {
+                        uwriteln!(
+                            self.r#gen.c_src.src,
+                            "return {wasm_sig}(({}){param});",
+                            self.r#gen.opts.ptr_type(),
+                        );
+                    }
                 }
                 SpecialMethod::ResourceRep => {
                     let module_name =
@@ -1405,17 +1673,26 @@ impl CppInterfaceGenerator<'_> {
                         &[WasmType::Pointer],
                     );
                     let classname = class_namespace(self, func, variant).join("::");
-                    uwriteln!(
-                        self.r#gen.c_src.src,
-                        "return ({}*){wasm_sig}({});",
-                        classname,
-                        func.params.first().unwrap().name
-                    );
+                    let param = &func.params.first().unwrap().name;
+                    if self.r#gen.opts.resource_table == ResourceTableKind::Slab {
+                        uwriteln!(
+                            self.r#gen.c_src.src,
+                            "return wit::{RESOURCE_TABLE_NAME}<{classname}*>::get_resource((int32_t)(uintptr_t){wasm_sig}({param}));"
+                        );
+                    } else {
+                        uwriteln!(
+                            self.r#gen.c_src.src,
+                            "return ({classname}*){wasm_sig}({param});"
+                        );
+                    }
                 }
                 SpecialMethod::Allocate => unreachable!(),
                 SpecialMethod::None => {
                     // normal methods
-                    let namespace = if matches!(func.kind, FunctionKind::Freestanding) {
+                    let namespace = if matches!(
+                        func.kind,
+                        FunctionKind::Freestanding | FunctionKind::AsyncFreestanding
+                    ) {
                         namespace(
                             self.resolve,
                             owner,
@@ -1424,13 +1701,14 @@ impl CppInterfaceGenerator<'_> {
                         )
                     } else {
                         let owner = &self.resolve.types[match &func.kind {
-                            FunctionKind::Static(id) => *id,
-                            FunctionKind::Constructor(id) => *id,
-                            FunctionKind::Method(id) => *id,
-                            FunctionKind::Freestanding => unreachable!(),
-                            FunctionKind::AsyncFreestanding => todo!(),
-                            FunctionKind::AsyncMethod(_id) => todo!(),
-                            FunctionKind::AsyncStatic(_id) => todo!(),
+                            FunctionKind::Static(id)
+                            | FunctionKind::AsyncStatic(id)
+                            | FunctionKind::Constructor(id)
+                            | FunctionKind::Method(id)
+                            | FunctionKind::AsyncMethod(id) => *id,
+                            FunctionKind::Freestanding | FunctionKind::AsyncFreestanding => {
+                                unreachable!()
+                            }
                         }]
                         .clone();
                         let mut namespace = namespace(
@@ -1442,22 +1720,47 @@ impl CppInterfaceGenerator<'_> {
                         namespace.push(owner.name.as_ref().unwrap().to_upper_camel_case());
                         namespace
                     };
-                    let mut f = FunctionBindgen::new(self, params);
-                    if !export {
-                        f.namespace = namespace.clone();
+                    self.generate_futures_and_streams(func, variant);
+                    if async_ && !export {
+                        self.generate_async_import_body(func, namespace, params);
+                    } else {
+                        let abi_variant = if async_ {
+                            AbiVariant::GuestExportAsync
+                        } else {
+                            variant
+                        };
+                        let thrown_result = self.thrown_result(func, async_);
+                        let mut f = FunctionBindgen::new(self, params);
+                        if !export {
+                            f.namespace = namespace.clone();
+                        }
+                        f.variant = variant;
+                        f.thrown_result = thrown_result;
+                        f.needs_dealloc = needs_dealloc;
+                        f.cabi_post = None;
+                        abi::call(
+                            f.r#gen.resolve,
+                            abi_variant,
+                            lift_lower,
+                            func,
+                            &mut f,
+                            async_,
+                        );
+                        let ret_area_decl = f.emit_ret_area_if_needed();
+                        let buffers_decl = f.emit_argument_buffers_if_needed();
+                        let code =
+                            format!("{}{}{}", ret_area_decl, buffers_decl, String::from(f.src));
+                        self.r#gen.c_src.src.push_str(&code);
                     }
-                    f.variant = variant;
-                    f.needs_dealloc = needs_dealloc;
-                    f.cabi_post = None;
-                    abi::call(f.r#gen.resolve, variant, lift_lower, func, &mut f, false);
-                    let ret_area_decl = f.emit_ret_area_if_needed();
-                    let code = format!("{}{}", ret_area_decl, String::from(f.src));
-                    self.r#gen.c_src.src.push_str(&code);
                 }
             }
             self.r#gen.c_src.src.push_str("}\n");
+            if async_ && export {
+                self.generate_async_export_callback(func);
+            }
             // cabi_post
             if matches!(variant, AbiVariant::GuestExport)
+                && !async_
                 && abi::guest_export_needs_post_return(self.resolve, func)
             {
                 let sig = self.resolve.wasm_signature(variant, func);
@@ -1503,6 +1806,196 @@ impl CppInterfaceGenerator<'_> {
         }
     }
 
+    /// The `result` type returned by `func` if its `err` case is thrown as
+    /// `wit::error<E>` instead of being returned in a `std::expected`.
+    fn thrown_result(&self, func: &Function, async_: bool) -> Option<TypeId> {
+        if self.r#gen.opts.error_style != ErrorStyle::Exceptions || async_ {
+            return None;
+        }
+        let Some(Type::Id(id)) = func.result else {
+            return None;
+        };
+        let id = dealias(self.resolve, id);
+        matches!(self.resolve.types[id].kind, TypeDefKind::Result(_)).then_some(id)
+    }
+
+    /// Whether `func` is bound as a coroutine, constructors and the
+    /// functions synthesized for resources are always synchronous.
+    fn is_async(&mut self, func: &Function, variant: AbiVariant) -> bool {
+        if matches!(func.kind, FunctionKind::Constructor(_))
+            || !matches!(is_special_method(func), SpecialMethod::None)
+        {
+            return false;
+        }
+        // world level functions aren't named by their interface
+        let interface = self.interface.and(self.name);
+        self.r#gen.opts.async_.is_async(
+            self.resolve,
+            interface,
+            func,
+            matches!(variant, AbiVariant::GuestImport),
+        )
+    }
+
+    /// The body of an async import: lower the arguments, start the subtask
+    /// and lift the result once it returned.
+    fn generate_async_import_body(
+        &mut self,
+        func: &Function,
+        namespace: Vec<String>,
+        params: Vec<String>,
+    ) {
+        let sig = self
+            .resolve
+            .wasm_signature(AbiVariant::GuestImportAsync, func);
+        let module_name = self
+            .r#gen
+            .import_prefix
+            .as_ref()
+            .cloned()
+            .unwrap_or_default()
+            + self.wasm_import_module.as_deref().unwrap_or("$root");
+        let import = self.declare_import(
+            &module_name,
+            &format!("[async-lower]{}", func.name),
+            &sig.params,
+            &sig.results,
+        );
+        let param_types = func.params.iter().map(|p| p.ty).collect::<Vec<_>>();
+        let mut f = FunctionBindgen::new(self, params.clone());
+        f.namespace = namespace;
+        // the arguments are owned by the coroutine and handed over
+        f.variant = AbiVariant::GuestExport;
+        // The lowered parameters and the results live on the heap, a
+        // cancelled subtask keeps them alive until it reached a final state,
+        // even if the coroutine frame is destroyed before.
+        let mut buffer_types = Vec::new();
+        if sig.indirect_params {
+            buffer_types.extend(param_types.iter().copied());
+        }
+        buffer_types.extend(func.result);
+        let buffer = !buffer_types.is_empty();
+        let mut offsets = f.r#gen.sizes.field_offsets(&buffer_types).into_iter();
+        if buffer {
+            uwriteln!(
+                f.src,
+                "auto _buffer = wit::detail::subtask_buffer({});",
+                f.r#gen
+                    .sizes
+                    .record(&buffer_types)
+                    .size
+                    .format(POINTER_SIZE_EXPRESSION)
+            );
+        }
+        let mut args = Vec::new();
+        let lowered = if sig.indirect_params {
+            uwriteln!(f.src, "uint8_t *_params = _buffer.get();");
+            for ((offset, ty), param) in offsets.by_ref().take(params.len()).zip(params) {
+                let address = format!("(_params + {})", offset.format(POINTER_SIZE_EXPRESSION));
+                abi::lower_to_memory(f.r#gen.resolve, &mut f, address, param, ty);
+            }
+            args.push(String::from("_params"));
+            args.clone()
+        } else {
+            for (param, ty) in params.into_iter().zip(&param_types) {
+                for value in abi::lower_flat(f.r#gen.resolve, &mut f, param, ty) {
+                    let arg = format!("_arg{}", args.len());
+                    let wasm_type = wit_bindgen_c::wasm_type(sig.params[args.len()]);
+                    uwriteln!(f.src, "{wasm_type} {arg} = {value};");
+                    args.push(arg);
+                }
+            }
+            args.clone()
+        };
+        if let Some((offset, _)) = offsets.next() {
+            uwriteln!(
+                f.src,
+                "uint8_t *_results = _buffer.get() + {};",
+                offset.format(POINTER_SIZE_EXPRESSION)
+            );
+            args.push(String::from("_results"));
+        }
+        uwriteln!(f.src, "uint32_t _status = {import}({});", args.join(", "));
+        // The lowered arguments are released once the subtask returned or
+        // was cancelled, owned handles stay ours if it never started.
+        let body = std::mem::take(&mut f.src);
+        abi::deallocate_lists_in_types(
+            f.r#gen.resolve,
+            &param_types,
+            &lowered,
+            sig.indirect_params,
+            &mut f,
+        );
+        let lists = String::from(std::mem::take(&mut f.src));
+        abi::deallocate_lists_and_own_in_types(
+            f.r#gen.resolve,
+            &param_types,
+            &lowered,
+            sig.indirect_params,
+            &mut f,
+        );
+        let lists_and_own = String::from(std::mem::replace(&mut f.src, body));
+        let buffer = if buffer { ", _buffer" } else { "" };
+        if lists_and_own.trim().is_empty() {
+            uwriteln!(
+                f.src,
+                "co_await wit::detail::Subtask(_status, nullptr{buffer});"
+            );
+        } else if lists.trim().is_empty() {
+            uwriteln!(
+                f.src,
+                "co_await wit::detail::Subtask(_status, [=](bool _started) {{
+                    if (!_started) {{
+                        {lists_and_own}
+                    }}
+                }}{buffer});"
+            );
+        } else {
+            uwriteln!(
+                f.src,
+                "auto _release = [=](bool _started) {{
+                    if (_started) {{
+                        {lists}
+                    }} else {{
+                        {lists_and_own}
+                    }}
+                }};
+                co_await wit::detail::Subtask(_status, _release{buffer});
+                _release(true);"
+            );
+        }
+        f.variant = AbiVariant::GuestImport;
+        match &func.result {
+            Some(ty) => {
+                let result = abi::lift_from_memory(f.r#gen.resolve, &mut f, "_results".into(), ty);
+                uwriteln!(f.src, "co_return {result};");
+            }
+            None => uwriteln!(f.src, "co_return;"),
+        }
+        let code = String::from(f.src);
+        self.r#gen.c_src.src.push_str(&code);
+    }
+
+    /// Async exports are driven by the canonical ABI callback which
+    /// delivers the events of their waitable set.
+    fn generate_async_export_callback(&mut self, func: &Function) {
+        let (export_name, symbol) = match &self.wasm_import_module {
+            Some(module_name) => (
+                format!("{module_name}#{}", func.name),
+                make_external_symbol(module_name, &func.name, AbiVariant::GuestExport),
+            ),
+            None => (func.name.clone(), make_external_component(&func.name)),
+        };
+        let prefix = self.r#gen.opts.export_prefix.clone().unwrap_or_default();
+        uwriteln!(
+            self.r#gen.c_src.src,
+            r#"extern "C" __attribute__((__export_name__("[callback][async-lift]{export_name}")))
+            uint32_t __wasm_export_{prefix}{symbol}_callback(uint32_t event, uint32_t waitable, uint32_t code) {{
+                return wit::detail::export_callback(event, waitable, code);
+            }}"#
+        );
+    }
+
     // in C this is print_optional_ty
     fn optional_type_name(
         &mut self,
@@ -1590,6 +2083,10 @@ impl CppInterfaceGenerator<'_> {
                     self.r#gen.dependencies.needs_string_view = true;
                     "std::string_view".into()
                 }
+                _ if self.r#gen.opts.std_types => {
+                    self.r#gen.dependencies.needs_string = true;
+                    "std::string".into()
+                }
                 Flavor::Argument(AbiVariant::GuestExport) => {
                     self.r#gen.dependencies.needs_wit = true;
                     "wit::string".into()
@@ -1710,6 +2207,9 @@ impl CppInterfaceGenerator<'_> {
                     // to get std::string_view instead of wit::string. Otherwise use InStruct
                     // flavor to avoid adding && to owned resources (lists contain values, not rvalue references)
                     let element_flavor = match flavor {
+                        Flavor::Argument(AbiVariant::GuestImport) if self.r#gen.opts.std_types => {
+                            Flavor::InStruct
+                        }
                         Flavor::BorrowedArgument | Flavor::Argument(AbiVariant::GuestImport) => {
                             Flavor::BorrowedArgument
                         }
@@ -1734,6 +2234,10 @@ impl CppInterfaceGenerator<'_> {
                             };
                             format!("std::span<{inner}{constness}>")
                         }
+                        _ if self.r#gen.opts.std_types => {
+                            self.r#gen.dependencies.needs_vector = true;
+                            format!("std::vector<{inner}>")
+                        }
                         Flavor::Argument(AbiVariant::GuestExport) => {
                             self.r#gen.dependencies.needs_wit = true;
                             format!("wit::vector<{inner}>")
@@ -1744,8 +2248,14 @@ impl CppInterfaceGenerator<'_> {
                         }
                     }
                 }
-                TypeDefKind::Future(_) => todo!(),
-                TypeDefKind::Stream(_) => todo!(),
+                TypeDefKind::Future(ty) => {
+                    let payload = self.payload_type_name(ty.as_ref(), from_namespace);
+                    format!("wit::FutureReader<{payload}>")
+                }
+                TypeDefKind::Stream(ty) => {
+                    let payload = self.payload_type_name(ty.as_ref(), from_namespace);
+                    format!("wit::StreamReader<{payload}>")
+                }
                 TypeDefKind::Type(ty) => self.type_name(ty, from_namespace, flavor),
                 TypeDefKind::FixedLengthList(ty, size) => {
                     self.r#gen.dependencies.needs_array = true;
@@ -1763,14 +2273,22 @@ impl CppInterfaceGenerator<'_> {
                         }
                         _ => false,
                     };
-                    let element_flavor = if borrowed {
+                    let element_flavor = if borrowed && !self.r#gen.opts.std_types {
                         Flavor::BorrowedArgument
                     } else {
                         Flavor::InStruct
                     };
                     let k = self.type_name(key, from_namespace, element_flavor);
                     let v = self.type_name(value, from_namespace, element_flavor);
-                    if borrowed {
+                    if self.r#gen.opts.std_types {
+                        self.r#gen.dependencies.needs_unordered_map = true;
+                        let map = format!("std::unordered_map<{k}, {v}>");
+                        if borrowed && !matches!(flavor, Flavor::BorrowedArgument) {
+                            format!("{map} const&")
+                        } else {
+                            map
+                        }
+                    } else if borrowed {
                         self.r#gen.dependencies.needs_span = true;
                         format!("std::span<std::pair<{k}, {v}> const>")
                     } else {
@@ -1784,6 +2302,15 @@ impl CppInterfaceGenerator<'_> {
         }
     }
 
+    /// The C++ type transferred by a future or stream, `wit::Void` if there is no payload
+    fn payload_type_name(&mut self, ty: Option<&Type>, from_namespace: &[String]) -> String {
+        self.r#gen.dependencies.needs_wit = true;
+        match ty {
+            Some(ty) => self.type_name(ty, from_namespace, Flavor::InStruct),
+            None => "wit::Void".into(),
+        }
+    }
+
     fn declare_import2(
         &self,
         module_name: &str,
@@ -1869,6 +2396,173 @@ impl CppInterfaceGenerator<'_> {
         }
     }
 
+    /// Declares a named type as a `using` alias of its C++ type
+    fn type_using_alias(&mut self, id: TypeId, name: &str, docs: &Docs) {
+        let ty = &self.resolve.types[id];
+        let guest_export = self.is_exported_type(ty);
+        let namespc = namespace(self.resolve, &ty.owner, guest_export, &*self.r#gen);
+        self.r#gen.h_src.change_namespace(&namespc);
+        let pascal = name.to_pascal_case();
+        Self::docs(&mut self.r#gen.h_src.src, docs);
+        let typename = self.type_name(&Type::Id(id), &namespc, Flavor::InStruct);
+        uwriteln!(self.r#gen.h_src.src, "using {pascal} = {typename};");
+    }
+
+    /// Provides the `wit::future_vtable`/`wit::stream_vtable` specializations
+    /// for the futures and streams passed to or returned from `func`.
+    ///
+    /// The vtables are shared by all handles with the same payload type, the
+    /// intrinsics are imported for the first function using them.
+    fn generate_futures_and_streams(&mut self, func: &Function, variant: AbiVariant) {
+        let module = match variant {
+            AbiVariant::GuestImport => self
+                .wasm_import_module
+                .clone()
+                .unwrap_or_else(|| "$root".into()),
+            _ => format!(
+                "[export]{}",
+                self.wasm_import_module.as_deref().unwrap_or("$root")
+            ),
+        };
+        // the specializations live in namespace wit
+        let wit_namespace = vec![String::from("wit")];
+        for (index, id) in func
+            .find_futures_and_streams(self.resolve)
+            .into_iter()
+            .enumerate()
+        {
+            let (kind, vtable, payload) = match &self.resolve.types[id].kind {
+                TypeDefKind::Future(payload) => ("future", "FutureVtable", payload),
+                TypeDefKind::Stream(payload) => ("stream", "StreamVtable", payload),
+                _ => unreachable!(),
+            };
+            let payload_name = self.payload_type_name(payload.as_ref(), &wit_namespace);
+            if !self.r#gen.vtables.insert(format!("{kind}<{payload_name}>")) {
+                continue;
+            }
+            let func_name = &func.name;
+            let len_arg = if kind == "stream" { ", size_t" } else { "" };
+            let mut import = |name: &str, args: &str, result: &str| {
+                let (extern_name, code) = self.declare_import2(
+                    &module,
+                    &format!("{name}{func_name}"),
+                    args,
+                    result,
+                    AbiVariant::GuestImport,
+                );
+                self.r#gen.extern_c_decls.push_str(&code);
+                extern_name
+            };
+            let new = import(&format!("[{kind}-new-{index}]"), "", "uint64_t");
+            let start_read = import(
+                &format!("[async-lower][{kind}-read-{index}]"),
+                &format!("uint32_t, uint8_t*{len_arg}"),
+                "uint32_t",
+            );
+            let start_write = import(
+                &format!("[async-lower][{kind}-write-{index}]"),
+                &format!("uint32_t, uint8_t const*{len_arg}"),
+                "uint32_t",
+            );
+            let cancel_read = import(
+                &format!("[{kind}-cancel-read-{index}]"),
+                "uint32_t",
+                "uint32_t",
+            );
+            let cancel_write = import(
+                &format!("[{kind}-cancel-write-{index}]"),
+                "uint32_t",
+                "uint32_t",
+            );
+            let drop_readable = import(
+                &format!("[{kind}-drop-readable-{index}]"),
+                "uint32_t",
+                "void",
+            );
+            let drop_writable = import(
+                &format!("[{kind}-drop-writable-{index}]"),
+                "uint32_t",
+                "void",
+            );
+
+            let (size, lift, lower, dealloc) = match payload {
+                Some(ty) => {
+                    let size = self.sizes.size(ty).format(POINTER_SIZE_EXPRESSION);
+
+                    let mut f = FunctionBindgen::new(self, Vec::new());
+                    f.namespace = wit_namespace.clone();
+                    f.variant = AbiVariant::GuestExport;
+                    let result = abi::lift_from_memory(f.r#gen.resolve, &mut f, "ptr".into(), ty);
+                    // remove the std::move due to return value optimization
+                    let result = if result.starts_with("std::move(") && !result.contains('.') {
+                        result[9..].to_string()
+                    } else {
+                        result
+                    };
+                    let lift = format!("{}return {result};\n", String::from(f.src));
+
+                    let mut f = FunctionBindgen::new(self, Vec::new());
+                    f.namespace = wit_namespace.clone();
+                    f.variant = AbiVariant::GuestExport;
+                    abi::lower_to_memory(f.r#gen.resolve, &mut f, "ptr".into(), "value".into(), ty);
+                    let lower = String::from(f.src);
+
+                    let mut f = FunctionBindgen::new(self, Vec::new());
+                    f.namespace = wit_namespace.clone();
+                    f.variant = AbiVariant::GuestExport;
+                    abi::deallocate_lists_in_types(
+                        f.r#gen.resolve,
+                        &[*ty],
+                        &["ptr".into()],
+                        true,
+                        &mut f,
+                    );
+                    let dealloc = String::from(f.src);
+                    (size, lift, lower, dealloc)
+                }
+                None => (
+                    "0".into(),
+                    "return wit::Void{};\n".into(),
+                    String::new(),
+                    String::new(),
+                ),
+            };
+            uwriteln!(
+                self.r#gen.vtables_h,
+                "template <> {vtable}<{payload_name}> const &{kind}_vtable<{payload_name}>();"
+            );
+            uwriteln!(
+                self.r#gen.vtables_c,
+                "template <> {vtable}<{payload_name}> const &{kind}_vtable<{payload_name}>() {{
+                    static {vtable}<{payload_name}> const vtable = {{
+                        {size},
+                        {new},
+                        {start_read},
+                        {start_write},
+                        {cancel_read},
+                        {cancel_write},
+                        {drop_readable},
+                        {drop_writable},
+                        [](uint8_t *ptr) -> {payload_name} {{
+                            (void) ptr;
+                            {lift}
+                        }},
+                        []({payload_name} &&value, uint8_t *ptr) {{
+                            (void) value;
+                            (void) ptr;
+                            {lower}
+                        }},
+                        [](uint8_t *ptr) {{
+                            (void) ptr;
+                            {dealloc}
+                        }},
+                    }};
+                    return vtable;
+                }}"
+            );
+        }
+    }
+
     fn is_exported_type(&self, ty: &TypeDef) -> bool {
         match ty.owner {
             TypeOwner::Interface(intf) => {
@@ -2000,13 +2694,12 @@ impl<'a> wit_bindgen_core::InterfaceGenerator<'a> for CppInterfaceGenerator<'a>
             let funcs = self.resolve.interfaces[intf].functions.values();
             for func in funcs {
                 if match &func.kind {
-                    FunctionKind::Freestanding => false,
-                    FunctionKind::Method(mid) => *mid == id,
-                    FunctionKind::Static(mid) => *mid == id,
-                    FunctionKind::Constructor(mid) => *mid == id,
-                    FunctionKind::AsyncFreestanding => todo!(),
-                    FunctionKind::AsyncMethod(_id) => todo!(),
-                    FunctionKind::AsyncStatic(_id) => todo!(),
+                    FunctionKind::Freestanding | FunctionKind::AsyncFreestanding => false,
+                    FunctionKind::Method(mid)
+                    | FunctionKind::AsyncMethod(mid)
+                    | FunctionKind::Static(mid)
+                    | FunctionKind::AsyncStatic(mid)
+                    | FunctionKind::Constructor(mid) => *mid == id,
                 } {
                     self.generate_function(func, &TypeOwner::Interface(intf), variant);
                     // For non-fallible constructors on export side, generate a New allocator method
@@ -2290,13 +2983,13 @@ impl<'a> wit_bindgen_core::InterfaceGenerator<'a> for CppInterfaceGenerator<'a>
 
     fn type_fixed_length_list(
         &mut self,
-        _id: TypeId,
-        _name: &str,
+        id: TypeId,
+        name: &str,
         _ty: &wit_bindgen_core::wit_parser::Type,
         _size: u32,
-        _docs: &wit_bindgen_core::wit_parser::Docs,
+        docs: &wit_bindgen_core::wit_parser::Docs,
     ) {
-        todo!("named fixed-length list types are not yet supported in the C++ backend")
+        self.type_using_alias(id, name, docs);
     }
 
     fn type_map(
@@ -2320,12 +3013,12 @@ impl<'a> wit_bindgen_core::InterfaceGenerator<'a> for CppInterfaceGenerator<'a>
         todo!()
     }
 
-    fn type_future(&mut self, _id: TypeId, _name: &str, _ty: &Option<Type>, _docs: &Docs) {
-        todo!()
+    fn type_future(&mut self, id: TypeId, name: &str, _ty: &Option<Type>, docs: &Docs) {
+        self.type_using_alias(id, name, docs);
     }
 
-    fn type_stream(&mut self, _id: TypeId, _name: &str, _ty: &Option<Type>, _docs: &Docs) {
-        todo!()
+    fn type_stream(&mut self, id: TypeId, name: &str, _ty: &Option<Type>, docs: &Docs) {
+        self.type_using_alias(id, name, docs);
     }
 }
 
@@ -2352,6 +3045,12 @@ struct FunctionBindgen<'a, 'b> {
     leak_on_insertion: Option<String>,
     return_pointer_area_size: ArchitectureSize,
     return_pointer_area_align: Alignment,
+    /// the function's `result` whose `err` case is thrown as `wit::error`
+    thrown_result: Option<TypeId>,
+    /// export result and error caught from the implementation
+    caught: Option<String>,
+    /// `std::vector` arguments were lowered into `_buffers`
+    needs_argument_buffers: bool,
 }
 
 impl<'a, 'b> FunctionBindgen<'a, 'b> {
@@ -2371,6 +3070,9 @@ impl<'a, 'b> FunctionBindgen<'a, 'b> {
             leak_on_insertion: None,
             return_pointer_area_size: Default::default(),
             return_pointer_area_align: Default::default(),
+            thrown_result: None,
+            caught: None,
+            needs_argument_buffers: false,
         }
     }
 
@@ -2443,6 +3145,15 @@ impl<'a, 'b> FunctionBindgen<'a, 'b> {
         );
     }
 
+    /// Declares the owner of the buffers `std::vector` arguments were lowered into.
+    fn emit_argument_buffers_if_needed(&self) -> String {
+        if self.needs_argument_buffers {
+            String::from("wit::detail::ArgumentBuffers _buffers;\n")
+        } else {
+            String::new()
+        }
+    }
+
     /// Emits a shared return area declaration if needed by this function.
     ///
     /// During code generation, `return_pointer()` may be called multiple times for:
@@ -2596,6 +3307,49 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
             abi::Instruction::CoreF32FromF32 => top_as("float"),
             abi::Instruction::CoreF64FromF64 => top_as("double"),
             abi::Instruction::BoolFromI32 => top_as("bool"),
+            abi::Instruction::ListCanonLower { element, realloc }
+                if realloc.is_some() && self.r#gen.r#gen.opts.std_types =>
+            {
+                let tmp = self.tmp();
+                let val = format!("_vec{tmp}");
+                let ptr = format!("_ptr{tmp}");
+                let len = format!("_len{tmp}");
+                let inner = self
+                    .r#gen
+                    .type_name(element, &self.namespace, Flavor::InStruct);
+                self.r#gen.r#gen.dependencies.needs_cstring = true;
+                uwriteln!(
+                    self.src,
+                    "auto&& {val} = {};
+                    auto {len} = (size_t)({val}.size());
+                    auto {ptr} = ({})(cabi_realloc(nullptr, 0, alignof({inner}), {len} * sizeof({inner})));
+                    memcpy({ptr}, {val}.data(), {len} * sizeof({inner}));",
+                    operands[0],
+                    self.r#gen.r#gen.opts.ptr_type(),
+                );
+                results.push(ptr);
+                results.push(len);
+            }
+            abi::Instruction::StringLower { realloc }
+                if realloc.is_some() && self.r#gen.r#gen.opts.std_types =>
+            {
+                let tmp = self.tmp();
+                let val = format!("_vec{tmp}");
+                let ptr = format!("_ptr{tmp}");
+                let len = format!("_len{tmp}");
+                self.r#gen.r#gen.dependencies.needs_cstring = true;
+                uwriteln!(
+                    self.src,
+                    "auto&& {val} = {};
+                    auto {len} = (size_t)({val}.size());
+                    auto {ptr} = ({})(cabi_realloc(nullptr, 0, 1, {len}));
+                    memcpy({ptr}, {val}.data(), {len});",
+                    operands[0],
+                    self.r#gen.r#gen.opts.ptr_type(),
+                );
+                results.push(ptr);
+                results.push(len);
+            }
             abi::Instruction::ListCanonLower { realloc, .. } => {
                 let tmp = self.tmp();
                 let val = format!("_vec{tmp}");
@@ -2638,6 +3392,44 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                 }
                 results.push(len);
             }
+            abi::Instruction::ListLower { element, realloc } if self.r#gen.r#gen.opts.std_types => {
+                // the elements are lowered into a separate buffer, as the
+                // `std::` containers can't hand over their storage
+                let tmp = self.tmp();
+                let body = self.blocks.pop().unwrap();
+                let val = format!("_vec{tmp}");
+                let ptr = format!("_ptr{tmp}");
+                let len = format!("_len{tmp}");
+                let size = self.r#gen.sizes.size(element);
+                let align = self.r#gen.sizes.align(element);
+                let size = size.format(POINTER_SIZE_EXPRESSION);
+                let buffer = if realloc.is_some() {
+                    format!(
+                        "({})(cabi_realloc(nullptr, 0, {}, {len} * {size}))",
+                        self.r#gen.r#gen.opts.ptr_type(),
+                        align.format(POINTER_SIZE_EXPRESSION)
+                    )
+                } else {
+                    self.needs_argument_buffers = true;
+                    self.r#gen.r#gen.dependencies.needs_wit = true;
+                    format!("_buffers.allocate({len} * {size})")
+                };
+                uwriteln!(
+                    self.src,
+                    "auto&& {val} = {};
+                    auto {len} = (size_t)({val}.size());
+                    auto {ptr} = {buffer};
+                    for (size_t i = 0; i < {len}; ++i) {{
+                        auto _base = {ptr} + i * {size};
+                        auto&& _iter_elem = {val}[i];
+                        {}
+                    }}",
+                    operands[0],
+                    body.0
+                );
+                results.push(ptr);
+                results.push(len);
+            }
             abi::Instruction::ListLower { element, realloc } => {
                 let tmp = self.tmp();
                 let body = self.blocks.pop().unwrap();
@@ -2676,7 +3468,19 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                     .r#gen
                     .type_name(element, &self.namespace, Flavor::InStruct);
                 self.push_str(&format!("auto {} = {};\n", len, operands[1]));
-                let result = if self.r#gen.r#gen.opts.api_style == APIStyle::Symmetric
+                let result = if self.r#gen.r#gen.opts.std_types {
+                    // copy and free the lifted buffer
+                    let base = format!("_base{tmp}");
+                    let result = format!("_result{tmp}");
+                    uwriteln!(
+                        self.src,
+                        "auto {base} = ({inner}*)({});
+                        std::vector<{inner}> {result}({base}, {base} + {len});
+                        if ({len} > 0) free((void*){base});",
+                        operands[0]
+                    );
+                    move_if_necessary(&result)
+                } else if self.r#gen.r#gen.opts.api_style == APIStyle::Symmetric
                     && matches!(self.variant, AbiVariant::GuestExport)
                 {
                     format!(
@@ -2702,11 +3506,54 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                         operands[0]
                     );
                     format!("std::string_view((char const*)({}), {len})", operands[0])
+                } else if self.r#gen.r#gen.opts.std_types {
+                    // copy and free the lifted buffer
+                    let ptr = format!("_ptr{tmp}");
+                    let result = format!("_string{tmp}");
+                    uwriteln!(
+                        self.src,
+                        "auto {ptr} = (char const*)({});
+                        std::string {result}({ptr}, {len});
+                        if ({len} > 0) free((void*){ptr});",
+                        operands[0]
+                    );
+                    move_if_necessary(&result)
                 } else {
                     format!("wit::string((char const*)({}), {len})", operands[0])
                 };
                 results.push(result);
             }
+            abi::Instruction::ListLift { element, .. } if self.r#gen.r#gen.opts.std_types => {
+                // the elements are moved out of the lifted buffer, which is freed
+                let body = self.blocks.pop().unwrap();
+                let tmp = self.tmp();
+                let size = self.r#gen.sizes.size(element);
+                let vtype = self
+                    .r#gen
+                    .type_name(element, &self.namespace, Flavor::InStruct);
+                let len = format!("_len{tmp}");
+                let base = format!("_base{tmp}");
+                let result = format!("_result{tmp}");
+                uwriteln!(
+                    self.src,
+                    "auto {base} = {};
+                    auto {len} = {};
+                    std::vector<{vtype}> {result};
+                    {result}.reserve({len});
+                    for (unsigned i=0; i<{len}; ++i) {{
+                        auto _base = {base} + i * {size};
+                        {}
+                        {result}.push_back({});
+                    }}
+                    if ({len} > 0) free((void*){base});",
+                    operands[0],
+                    operands[1],
+                    body.0,
+                    move_if_necessary(&body.1[0]),
+                    size = size.format(POINTER_SIZE_EXPRESSION)
+                );
+                results.push(move_if_necessary(&result));
+            }
             abi::Instruction::ListLift { element, .. } => {
                 let body = self.blocks.pop().unwrap();
                 let tmp = self.tmp();
@@ -2800,6 +3647,7 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                 element,
                 size: elemsize,
                 id: _,
+                offset: _,
             } => {
                 let body = self.blocks.pop().unwrap();
                 let tmp = self.tmp();
@@ -2838,6 +3686,7 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                 element,
                 size: elemsize,
                 id: _,
+                offset: _,
             } => {
                 let body = self.blocks.pop().unwrap();
                 let vec = operands[0].clone();
@@ -3001,7 +3850,13 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                                 &self.namespace,
                                 Flavor::Argument(self.variant),
                             );
-                            results.push(format!("std::ref(*({tname} *){op})"));
+                            if self.r#gen.r#gen.opts.resource_table == ResourceTableKind::Slab {
+                                results.push(format!(
+                                    "std::ref(*wit::{RESOURCE_TABLE_NAME}<{tname}*>::get_resource((int32_t)(uintptr_t){op}))"
+                                ));
+                            } else {
+                                results.push(format!("std::ref(*({tname} *){op})"));
+                            }
                         }
                         AbiVariant::GuestImportAsync => todo!(),
                         AbiVariant::GuestExportAsync => todo!(),
@@ -3206,6 +4061,7 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                 let (flavor, value_extract) = if matches!(payload, Type::String)
                     && matches!(self.variant, AbiVariant::GuestImport)
                     && !is_function_param
+                    && !self.r#gen.r#gen.opts.std_types
                 {
                     // Import from struct/variant field: optional<wit::string> needs .get_view()
                     (
@@ -3268,7 +4124,7 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
             abi::Instruction::ResultLower {
                 results: result_types,
                 result,
-                ..
+                ty,
             } => {
                 let (mut err, err_results) = self.blocks.pop().unwrap();
                 let (mut ok, ok_results) = self.blocks.pop().unwrap();
@@ -3300,13 +4156,31 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                     &self.namespace,
                     Flavor::InStruct,
                 );
+                // the implementation's value or `wit::error` caught by `CallInterface`
+                let caught = if self.thrown_result == Some(*ty) {
+                    self.caught.take()
+                } else {
+                    None
+                };
+                let (has_value, value, error) = match &caught {
+                    Some(name) => (
+                        format!("!{name}_err.has_value()"),
+                        format!("std::move(*{name})"),
+                        format!("std::move(*{name}_err)"),
+                    ),
+                    None => (
+                        format!("({op0}).has_value()"),
+                        format!("std::move({op0}).value()"),
+                        format!("std::move({op0}).error()"),
+                    ),
+                };
                 let bind_ok = if let Some(_ok) = result.ok.as_ref() {
-                    format!("{ok_ty} {ok_payload} = std::move({op0}).value();")
+                    format!("{ok_ty} {ok_payload} = {value};")
                 } else {
                     String::new()
                 };
                 let bind_err = if let Some(_err) = result.err.as_ref() {
-                    format!("{err_ty} {err_payload} = std::move({op0}).error();")
+                    format!("{err_ty} {err_payload} = {error};")
                 } else {
                     String::new()
                 };
@@ -3314,7 +4188,7 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                 uwrite!(
                     self.src,
                     "\
-                    if (({op0}).has_value()) {{
+                    if ({has_value}) {{
                         {bind_ok}
                         {ok}}} else {{
                         {bind_err}
@@ -3322,7 +4196,7 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                     "
                 );
             }
-            abi::Instruction::ResultLift { result, .. } => {
+            abi::Instruction::ResultLift { result, ty } => {
                 let (mut err, err_results) = self.blocks.pop().unwrap();
                 let (mut ok, ok_results) = self.blocks.pop().unwrap();
                 let mut ok_result = String::new();
@@ -3347,32 +4221,49 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                 let err_type = result.err.as_ref().map_or(String::from("wit::Void"), |ty| {
                     self.r#gen.type_name(ty, &self.namespace, Flavor::InStruct)
                 });
-                let full_type = format!("std::expected<{ok_type}, {err_type}>",);
-                let err_type = "std::unexpected";
                 let operand = &operands[0];
 
-                let tmp = self.tmp();
-                let resultname = self.tempname("result", tmp);
-                // Use std::optional to avoid default constructor issues with std::expected
-                self.r#gen.r#gen.dependencies.needs_optional = true;
-                let ok_assign = if result.ok.is_some() {
-                    format!("{resultname}_opt.emplace({full_type}({ok_result}));")
+                if matches!(self.variant, AbiVariant::GuestImport)
+                    && self.thrown_result == Some(*ty)
+                {
+                    // the function returns the `ok` payload directly
+                    self.r#gen.r#gen.dependencies.needs_wit = true;
+                    uwriteln!(
+                        self.src,
+                        "if ({operand}!=0) {{
+                            {err}
+                            throw wit::error<{err_type}>({err_result});
+                        }}
+                        {ok}"
+                    );
+                    results.push(ok_result);
                 } else {
-                    format!("{resultname}_opt.emplace({full_type}());")
-                };
-                uwriteln!(
-                    self.src,
-                    "std::optional<{full_type}> {resultname}_opt;
-                    if ({operand}==0) {{
-                        {ok}
-                        {ok_assign}
-                    }} else {{
-                        {err}
-                        {resultname}_opt.emplace({err_type}{{{err_result}}});
-                    }}
-                    {full_type} {resultname} = std::move(*{resultname}_opt);"
-                );
-                results.push(resultname);
+                    let full_type = format!("std::expected<{ok_type}, {err_type}>",);
+                    let err_type = "std::unexpected";
+
+                    let tmp = self.tmp();
+                    let resultname = self.tempname("result", tmp);
+                    // Use std::optional to avoid default constructor issues with std::expected
+                    self.r#gen.r#gen.dependencies.needs_optional = true;
+                    let ok_assign = if result.ok.is_some() {
+                        format!("{resultname}_opt.emplace({full_type}({ok_result}));")
+                    } else {
+                        format!("{resultname}_opt.emplace({full_type}());")
+                    };
+                    uwriteln!(
+                        self.src,
+                        "std::optional<{full_type}> {resultname}_opt;
+                        if ({operand}==0) {{
+                            {ok}
+                            {ok_assign}
+                        }} else {{
+                            {err}
+                            {resultname}_opt.emplace({err_type}{{{err_result}}});
+                        }}
+                        {full_type} {resultname} = std::move(*{resultname}_opt);"
+                    );
+                    results.push(resultname);
+                }
             }
             abi::Instruction::CallWasm { name, sig } => {
                 let module_name = self
@@ -3410,11 +4301,46 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                 );
                 self.src.push_str(");\n");
             }
-            abi::Instruction::CallInterface { func, .. } => {
+            abi::Instruction::CallInterface { func, async_ } => {
                 // dbg!(func);
-                self.let_results(if func.result.is_some() { 1 } else { 0 }, results);
+                let thrown = self.thrown_result.map(|id| {
+                    let TypeDefKind::Result(r) = &self.r#gen.resolve.types[id].kind else {
+                        unreachable!()
+                    };
+                    let err_type = r.err.as_ref().map_or(String::from("wit::Void"), |ty| {
+                        self.r#gen.type_name(ty, &self.namespace, Flavor::InStruct)
+                    });
+                    let ok_type =
+                        r.ok.as_ref()
+                            .map(|ty| self.r#gen.type_name(ty, &self.namespace, Flavor::InStruct));
+                    (ok_type, err_type)
+                });
+                if *async_ {
+                    // the result is lowered and returned once the coroutine finished
+                    self.push_str("return wit::detail::start_export(");
+                } else if let Some((ok_type, err_type)) = &thrown {
+                    // keep the value or the caught error for `ResultLower`
+                    let tmp = self.tmp();
+                    let name = format!("result{tmp}");
+                    self.r#gen.r#gen.dependencies.needs_optional = true;
+                    uwriteln!(self.src, "std::optional<{err_type}> {name}_err;");
+                    if let Some(ok_type) = ok_type {
+                        uwriteln!(self.src, "std::optional<{ok_type}> {name};");
+                    }
+                    self.push_str("try {\n");
+                    if ok_type.is_some() {
+                        uwrite!(self.src, "{name}.emplace(");
+                    }
+                    self.caught = Some(name.clone());
+                    results.push(name);
+                } else {
+                    self.let_results(if func.result.is_some() { 1 } else { 0 }, results);
+                }
                 let (namespace, func_name_h) = self.r#gen.func_namespace_name(func, true, true);
-                if matches!(func.kind, FunctionKind::Method(_)) {
+                if matches!(
+                    func.kind,
+                    FunctionKind::Method(_) | FunctionKind::AsyncMethod(_)
+                ) {
                     let this = operands.remove(0);
                     uwrite!(self.src, "({this}).get().");
                 } else {
@@ -3431,7 +4357,47 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                         .collect::<Vec<_>>()
                         .join(", "),
                 );
-                self.push_str(");\n");
+                if *async_ {
+                    match &func.result {
+                        Some(ty) => {
+                            let tname = self.r#gen.type_name(ty, &self.namespace, Flavor::InStruct);
+                            uwriteln!(self.src, "), []({tname} result) {{");
+                            // `task.return` borrows the lowered lists for the call
+                            let has_list = match ty {
+                                Type::Id(id) => self.r#gen.r#gen.types.get(*id).has_list,
+                                ty => matches!(ty, Type::String),
+                            };
+                            if self.r#gen.r#gen.opts.std_types && has_list {
+                                self.r#gen.r#gen.dependencies.needs_wit = true;
+                                uwriteln!(self.src, "wit::detail::ArgumentBuffers _buffers;");
+                            }
+                            results.push("result".into());
+                        }
+                        None => uwriteln!(self.src, "), []() {{"),
+                    }
+                } else if let Some((ok_type, err_type)) = &thrown {
+                    let name = self.caught.clone().unwrap();
+                    if ok_type.is_some() {
+                        self.push_str(")");
+                    }
+                    let catch_all = match &self.r#gen.r#gen.opts.error_fallback {
+                        Some(fallback) => format!(
+                            "
+                            }} catch (...) {{
+                                {name}_err.emplace({fallback}<{err_type}>(std::current_exception()));"
+                        ),
+                        None => String::new(),
+                    };
+                    uwriteln!(
+                        self.src,
+                        ");
+                        }} catch (wit::error<{err_type}> &e) {{
+                            {name}_err.emplace(std::move(e).value());{catch_all}
+                        }}"
+                    );
+                } else {
+                    self.push_str(");\n");
+                }
                 if self.needs_dealloc {
                     uwriteln!(
                         self.src,
@@ -3443,6 +4409,8 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
             abi::Instruction::Return { amt, func } => {
                 match amt {
                     0 => {}
+                    // the `ok` case of a thrown `result<_, E>` has no value
+                    1 if operands[0].is_empty() => {}
                     _ => {
                         assert!(*amt == operands.len());
                         // Fallible constructors return expected, not void
@@ -3516,6 +4484,7 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                 uwriteln!(self.src, "free((void*) ({}));", operands[0]);
             }
             abi::Instruction::GuestDeallocateString => {
+                self.r#gen.r#gen.dependencies.needs_wit = true;
                 uwriteln!(self.src, "if (({}) > 0) {{", operands[1]);
                 uwriteln!(
                     self.src,
@@ -3576,22 +4545,42 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                 self.store(ptr_type, *offset, operands)
             }
             abi::Instruction::LengthStore { offset } => self.store("size_t", *offset, operands),
-            abi::Instruction::FutureLower { .. } => todo!(),
-            abi::Instruction::FutureLift { .. } => todo!(),
-            abi::Instruction::StreamLower { .. } => todo!(),
-            abi::Instruction::StreamLift { .. } => todo!(),
+            abi::Instruction::FutureLower { .. } | abi::Instruction::StreamLower { .. } => {
+                results.push(format!("(int32_t)({}).into_handle()", operands[0]));
+            }
+            abi::Instruction::FutureLift { ty, .. } | abi::Instruction::StreamLift { ty, .. } => {
+                let tname = self
+                    .r#gen
+                    .type_name(&Type::Id(*ty), &self.namespace, Flavor::InStruct);
+                results.push(format!("{tname}((uint32_t)({}))", operands[0]));
+            }
             abi::Instruction::ErrorContextLower { .. } => todo!(),
             abi::Instruction::ErrorContextLift { .. } => todo!(),
             abi::Instruction::Flush { amt } => {
                 for i in operands.iter().take(*amt) {
+                    // nothing to hold on to for a thrown `result<_, E>`
+                    if i.is_empty() {
+                        results.push(String::new());
+                        continue;
+                    }
                     let tmp = self.tmp();
                     let result = format!("result{tmp}");
                     uwriteln!(self.src, "auto {result} = {};", move_if_necessary(i));
                     results.push(result);
                 }
             }
-            abi::Instruction::AsyncTaskReturn { .. } => todo!(),
-            abi::Instruction::DropHandle { .. } => todo!(),
+            abi::Instruction::AsyncTaskReturn { name, params } => {
+                let module_name = format!(
+                    "[export]{}",
+                    self.r#gen.wasm_import_module.as_deref().unwrap_or("$root")
+                );
+                let func = self.r#gen.declare_import(&module_name, name, params, &[]);
+                uwriteln!(self.src, "{func}({});", operands.join(", "));
+                uwriteln!(self.src, "}});");
+            }
+            abi::Instruction::DropHandle { .. } => {
+                uwriteln!(self.src, "{{ auto _dropped = {}; }}", operands[0]);
+            }
             abi::Instruction::MapLower {
                 key,
                 value,
@@ -3610,25 +4599,73 @@ impl<'a, 'b> Bindgen for FunctionBindgen<'a, 'b> {
                 // buffer rather than reusing the source map's storage.
                 self.push_str(&format!("auto&& {val} = {};\n", operands[0]));
                 self.push_str(&format!("auto {len} = {val}.size();\n"));
-                uwriteln!(
-                    self.src,
-                    "auto {ptr} = static_cast<{ptr_type}>({len} > 0 ? cabi_realloc(nullptr, 0, {align}, {len} * {size}) : nullptr);",
-                    ptr_type = self.r#gen.r#gen.opts.ptr_type()
-                );
-                uwriteln!(self.src, "for (size_t i = 0; i < {len}; ++i) {{");
+                if realloc.is_none() && self.r#gen.r#gen.opts.std_types {
+                    self.needs_argument_buffers = true;
+                    self.r#gen.r#gen.dependencies.needs_wit = true;
+                    uwriteln!(self.src, "auto {ptr} = _buffers.allocate({len} * {size});");
+                } else {
+                    uwriteln!(
+                        self.src,
+                        "auto {ptr} = static_cast<{ptr_type}>({len} > 0 ? cabi_realloc(nullptr, 0, {align}, {len} * {size}) : nullptr);",
+                        ptr_type = self.r#gen.r#gen.opts.ptr_type()
+                    );
+                }
+                if self.r#gen.r#gen.opts.std_types {
+                    uwriteln!(self.src, "for (size_t i = 0; auto&& iter_entry : {val}) {{");
+                } else {
+                    uwriteln!(self.src, "for (size_t i = 0; i < {len}; ++i) {{");
+                }
                 uwriteln!(self.src, "auto _base = {ptr} + i * {size};");
                 uwriteln!(self.src, "(void) _base;");
-                uwriteln!(self.src, "auto&& iter_entry = {val}.data()[i];");
+                if !self.r#gen.r#gen.opts.std_types {
+                    uwriteln!(self.src, "auto&& iter_entry = {val}.data()[i];");
+                }
                 uwriteln!(self.src, "auto&& iter_map_key = iter_entry.first;");
                 uwriteln!(self.src, "auto&& iter_map_value = iter_entry.second;");
                 uwrite!(self.src, "{}", body.0);
+                if self.r#gen.r#gen.opts.std_types {
+                    uwriteln!(self.src, "++i;");
+                }
                 uwriteln!(self.src, "}}");
-                if realloc.is_some() {
+                if realloc.is_some() && !self.r#gen.r#gen.opts.std_types {
                     uwriteln!(self.src, "{}.leak();", operands[0]);
                 }
                 results.push(ptr);
                 results.push(len);
             }
+            abi::Instruction::MapLift { key, value, .. } if self.r#gen.r#gen.opts.std_types => {
+                let body = self.blocks.pop().unwrap();
+                let tmp = self.tmp();
+                let entry = self.r#gen.sizes.record([*key, *value]);
+                let size = entry.size.format(POINTER_SIZE_EXPRESSION);
+                let key_type = self.r#gen.type_name(key, &self.namespace, Flavor::InStruct);
+                let value_type = self
+                    .r#gen
+                    .type_name(value, &self.namespace, Flavor::InStruct);
+                let len = format!("len{tmp}");
+                let base = format!("base{tmp}");
+                let result = format!("result{tmp}");
+                uwriteln!(
+                    self.src,
+                    "auto {base} = {};
+                    auto {len} = {};
+                    std::unordered_map<{key_type}, {value_type}> {result};
+                    {result}.reserve({len});
+                    for (unsigned i=0; i<{len}; ++i) {{
+                        auto _base = {base} + i * {size};
+                        (void) _base;
+                        {}
+                        {result}.emplace({}, {});
+                    }}
+                    if ({len} > 0) free((void*){base});",
+                    operands[0],
+                    operands[1],
+                    body.0,
+                    move_if_necessary(&body.1[0]),
+                    move_if_necessary(&body.1[1]),
+                );
+                results.push(move_if_necessary(&result));
+            }
             abi::Instruction::MapLift { key, value, .. } => {
                 let body = self.blocks.pop().unwrap();
                 let tmp = self.tmp();
@@ -3774,6 +4811,13 @@ enum SpecialMethod {
     Allocate,     // internal: allocate new object (called from generated code)
 }
 
+fn uses_fixed_length_list(resolve: &Resolve, func: &Function) -> bool {
+    let mut live = LiveTypes::default();
+    live.add_func(resolve, func);
+    live.iter()
+        .any(|id| matches!(resolve.types[id].kind, TypeDefKind::FixedLengthList(..)))
+}
+
 fn is_special_method(func: &Function) -> SpecialMethod {
     if matches!(func.kind, FunctionKind::Static(_)) {
         if func.name.starts_with("[resource-drop]") {
@@ -94,6 +94,17 @@ public static class AsyncSupport
         Interop.WaitableJoin(readerWriterHandle, waitableHandle);
     }
 
+    // Stops waiting for the operation on `waitableHandle`, whose cancellation consumed its event.
+    internal static unsafe void Leave(int waitableHandle)
+    {
+        ContextTask* contextTaskPtr = ContextGet();
+        if (contextTaskPtr != null && pendingTasks.TryGetValue(contextTaskPtr->WaitableSetHandle, out var waitables))
+        {
+            waitables.Remove(waitableHandle, out _);
+        }
+        Interop.WaitableJoin(waitableHandle, 0);
+    }
+
     // TODO: Revisit this to see if we can remove it.
     // Only allow joining to a handle directly when there is no waitable.
     public static void Join(int handle) 
@@ -140,6 +151,14 @@ public static class AsyncSupport
         var waitables = pendingTasks[contextTaskPtr->WaitableSetHandle];
         var waitableInfoState = waitables[e.Waitable];
 
+        if (e.IsSubtask && e.SubtaskStatus.IsStarted)
+        {
+            // The callee has read its arguments, so any spilled parameters can be released.
+            waitableInfoState.Started();
+            waitables[e.Waitable] = waitableInfoState;
+            return (int)CallbackCode.Wait | (int)(contextTaskPtr->WaitableSetHandle << 4);
+        }
+
         if (e.IsDropped)
         {
             waitableInfoState.FutureStream!.OtherSideDropped();
@@ -160,6 +179,7 @@ public static class AsyncSupport
                         break;
 
                     case { IsReturned: true }:
+                        waitableInfoState.Started();
                         waitableInfoState.SetResult(e.WaitableCount);
                         Interop.SubtaskDrop(e.Waitable);
                         break;
@@ -170,7 +190,7 @@ public static class AsyncSupport
             }
             else
             {
-                if (e.IsDropped)
+                if (e.IsDropped && e.WaitableCount == 0)
                 {
                     waitableInfoState.SetException(new StreamDroppedException());
                 }
@@ -195,7 +215,9 @@ public static class AsyncSupport
     }
 
     // This method is unsafe because we are using unmanaged memory to store the context.
-    internal static unsafe Task TaskFromStatus(uint status)
+    // `onStarted` runs once the callee has read its arguments, i.e. when the subtask
+    // leaves the starting state, and is used to free spilled parameters.
+    internal static unsafe Task TaskFromStatus(uint status, Action? onStarted = null)
     {
         var subtaskStatus = new SubtaskStatus(status);
         status = status & 0xF;
@@ -203,18 +225,25 @@ public static class AsyncSupport
         var tcs = new TaskCompletionSource<int>();
         if (subtaskStatus.IsStarting || subtaskStatus.IsStarted)
         {
+            if (subtaskStatus.IsStarted)
+            {
+                onStarted?.Invoke();
+                onStarted = null;
+            }
+
             ContextTask* contextTaskPtr = ContextGet();
             if (contextTaskPtr == null)
             {
                 contextTaskPtr = AllocateAndSetNewContext();
             }
 
-            Join(subtaskStatus.Handle, contextTaskPtr->WaitableSetHandle, new WaitableInfoState(tcs));
+            Join(subtaskStatus.Handle, contextTaskPtr->WaitableSetHandle, new WaitableInfoState(tcs, onStarted));
 
             return tcs.Task;
         }
         else if (subtaskStatus.IsReturned)
         {
+            onStarted?.Invoke();
             tcs.SetResult(0);
             return tcs.Task;
         }
@@ -225,12 +254,18 @@ public static class AsyncSupport
     }
 
     // unsafe because we are using pointers.
-    public static unsafe Task<T> TaskFromStatus<T>(uint status, Func<T> liftFunc)
+    public static unsafe Task<T> TaskFromStatus<T>(uint status, Func<T> liftFunc, Action? onStarted = null)
     {
         var subtaskStatus = new SubtaskStatus(status);
 
         if (subtaskStatus.IsStarting || subtaskStatus.IsStarted)
         {
+            if (subtaskStatus.IsStarted)
+            {
+                onStarted?.Invoke();
+                onStarted = null;
+            }
+
             ContextTask* contextTaskPtr = ContextGet();
             if (contextTaskPtr == null) {
                 contextTaskPtr = AllocateAndSetNewContext();
@@ -238,12 +273,13 @@ public static class AsyncSupport
 
             var intTaskCompletionSource = new TaskCompletionSource<int>();
             var tcs = new LiftingTaskCompletionSource<T>(intTaskCompletionSource, liftFunc);
-            Join(subtaskStatus.Handle, contextTaskPtr->WaitableSetHandle, new WaitableInfoState(intTaskCompletionSource));
+            Join(subtaskStatus.Handle, contextTaskPtr->WaitableSetHandle, new WaitableInfoState(intTaskCompletionSource, onStarted));
 
             return tcs.Task;
         }
         else if (subtaskStatus.IsReturned)
         {
+            onStarted?.Invoke();
             var tcs = new TaskCompletionSource<T>();
             tcs.SetResult(liftFunc());
             return tcs.Task;
@@ -284,6 +320,28 @@ public static class AsyncSupport
         }
     }
 
+    // Awaits `task`, cancelling it through its cancel-read/cancel-write built-in
+    // if `cancellationToken` fires first.
+    internal static async Task<T> WithCancellation<T>(ComponentTask<T> task, CancellationToken cancellationToken)
+    {
+        if (!cancellationToken.CanBeCanceled || task.IsCompleted)
+        {
+            return await task;
+        }
+
+        using (cancellationToken.Register(() => task.Cancel()))
+        {
+            try
+            {
+                return await task;
+            }
+            catch (TaskCanceledException) when (cancellationToken.IsCancellationRequested)
+            {
+                throw new OperationCanceledException(cancellationToken);
+            }
+        }
+    }
+
     // unsafe because we are working with native memory.
     internal static unsafe ContextTask* AllocateAndSetNewContext()
     {
@@ -320,7 +378,9 @@ public class CancelableRead(IVTable cancelableVTable, int handle) : ICancelable
 {
     public uint Cancel()
     {
-        return cancelableVTable.CancelRead(handle);        
+        var status = cancelableVTable.CancelRead(handle);
+        AsyncSupport.Leave(handle);
+        return status;
     }
 }
 
@@ -328,7 +388,9 @@ public class CancelableWrite(IVTable cancelableVTable, int handle) : ICancelable
 {
     public uint Cancel()
     {
-        return cancelableVTable.CancelWrite(handle);        
+        var status = cancelableVTable.CancelWrite(handle);
+        AsyncSupport.Leave(handle);
+        return status;
     }
 }
 
@@ -549,6 +611,15 @@ public abstract class ReaderBase : IFutureStream
         {
             return ComponentTask<int>.FromResult((int)status.Count);
         }
+        if (status.IsDropped)
+        {
+            writerDropped = true;
+            if (status.Count > 0)
+            {
+                return ComponentTask<int>.FromResult((int)status.Count);
+            }
+            throw new StreamDroppedException();
+        }
 
         throw new NotImplementedException(status.State.ToString());
     }
@@ -595,6 +666,22 @@ public class FutureReader : ReaderBase
         return ReadInternal(IntPtr.Zero, 0, VTable);
     }
 
+    /// <summary>
+    /// Waits for the future to be written. Cancelling the token cancels the read.
+    /// </summary>
+    public Task ReadAsync(CancellationToken cancellationToken = default)
+    {
+        return AsyncSupport.WithCancellation(ReadInternal(IntPtr.Zero, 0, VTable), cancellationToken);
+    }
+
+    /// <summary>
+    /// Makes the future directly awaitable.
+    /// </summary>
+    public TaskAwaiter GetAwaiter()
+    {
+        return ReadAsync().GetAwaiter();
+    }
+
     internal override uint VTableRead(IntPtr ptr, int length)
     {
         return VTable.Read(Handle, ptr);
@@ -632,10 +719,9 @@ public class FutureReader<T>(int handle, FutureVTable vTable) : ReaderBase(handl
                     readTask.SetException(e);
                 }
             }
-            else if (!it.IsCanceled)
+            else if (it.IsFaulted)
             {
-                //TODO
-                throw new NotImplementedException("faulted future read not implemented");
+                readTask.SetException(it.Exception!.InnerException!);
             }
 
             foreach(var cleanup in cleanups)
@@ -646,6 +732,22 @@ public class FutureReader<T>(int handle, FutureVTable vTable) : ReaderBase(handl
         return readTask;
     }
 
+    /// <summary>
+    /// Reads the future's value. Cancelling the token cancels the read.
+    /// </summary>
+    public Task<T> ReadAsync(CancellationToken cancellationToken = default)
+    {
+        return AsyncSupport.WithCancellation(Read(), cancellationToken);
+    }
+
+    /// <summary>
+    /// Makes the future directly awaitable.
+    /// </summary>
+    public TaskAwaiter<T> GetAwaiter()
+    {
+        return ReadAsync().GetAwaiter();
+    }
+
     class DelegatingCancelable : ICancelable
     {
         private ComponentTask innerTask;
@@ -698,10 +800,15 @@ public class StreamReader : ReaderBase
     }
 }
 
-public class StreamReader<T>(int handle, StreamVTable vTable) :  ReaderBase(handle)
+public class StreamReader<T>(int handle, StreamVTable vTable) :  ReaderBase(handle), IAsyncEnumerable<T>
 {
     public StreamVTable VTable { get; private set; } = vTable;
 
+    /// <summary>
+    /// The number of elements requested by each read when enumerating the stream.
+    /// </summary>
+    public int BatchSize { get; set; } = 64;
+
     public ComponentTask<int> Read(T[] resultBuffer)
     {
         var cleanups = new List<Action>();
@@ -714,11 +821,6 @@ public class StreamReader<T>(int handle, StreamVTable vTable) :  ReaderBase(hand
             {
                 VTable.Lift(buf, resultBuffer);
             }
-            else if (!it.IsCanceled)
-            {
-                //TODO
-                throw new NotImplementedException("faulted stream read not implemented");
-            }
 
             foreach(var cleanup in cleanups)
             {
@@ -729,6 +831,46 @@ public class StreamReader<T>(int handle, StreamVTable vTable) :  ReaderBase(hand
         return task;
     }
 
+    /// <summary>
+    /// Reads into <paramref name="resultBuffer"/> and returns the number of elements read.
+    /// Cancelling the token cancels the read.
+    /// </summary>
+    public Task<int> ReadAsync(T[] resultBuffer, CancellationToken cancellationToken = default)
+    {
+        return AsyncSupport.WithCancellation(Read(resultBuffer), cancellationToken);
+    }
+
+    /// <summary>
+    /// Enumerates the stream, reading up to <see cref="BatchSize"/> elements at a time,
+    /// until the writer is dropped.
+    /// </summary>
+    public async IAsyncEnumerator<T> GetAsyncEnumerator(CancellationToken cancellationToken = default)
+    {
+        var buffer = new T[BatchSize];
+        while (true)
+        {
+            int count;
+            try
+            {
+                count = await ReadAsync(buffer, cancellationToken);
+            }
+            catch (StreamDroppedException)
+            {
+                count = -1;
+            }
+
+            if (count < 0)
+            {
+                yield break;
+            }
+
+            for (var i = 0; i < count; i++)
+            {
+                yield return buffer[i];
+            }
+        }
+    }
+
     internal override uint VTableRead(IntPtr ptr, int length)
     {
         return VTable.Read(Handle, ptr, (uint)length);
@@ -812,6 +954,15 @@ public abstract class WriterBase : IFutureStream
         {
             return ComponentTask<int>.FromResult((int)status.Count);
         }
+        if (status.IsDropped)
+        {
+            readerDropped = true;
+            if (status.Count > 0)
+            {
+                return ComponentTask<int>.FromResult((int)status.Count);
+            }
+            throw new StreamDroppedException();
+        }
 
         throw new NotImplementedException($"Unsupported write status {status.State}");
     }
@@ -887,6 +1038,14 @@ public class FutureWriter<T>(int handle, FutureVTable vTable) : WriterBase(handl
         return WriteInternal(cleanups => LowerPayload(payload, cleanups), 1, VTable);
     }
 
+    /// <summary>
+    /// Writes the future's value. Cancelling the token cancels the write.
+    /// </summary>
+    public Task WriteAsync(T payload, CancellationToken cancellationToken = default)
+    {
+        return AsyncSupport.WithCancellation(Write(payload), cancellationToken);
+    }
+
     internal override uint VTableWrite(IntPtr bufferPtr, int length)
     {
         return VTable.Write(Handle, bufferPtr);
@@ -937,11 +1096,91 @@ public class StreamWriter<T>(int handle, StreamVTable vTable) : WriterBase(handl
         }
     }
 
+    /// <summary>
+    /// The maximum number of elements passed to a single write by the <c>WriteAllAsync</c> overloads.
+    /// </summary>
+    public int BatchSize { get; set; } = 64;
+
     public ComponentTask<int> Write(T[] payload)
     {
         return WriteInternal(cleanups => LowerPayload(payload, cleanups), payload.Length, VTable);
     }
 
+    /// <summary>
+    /// Writes <paramref name="payload"/> and returns the number of elements the reader took.
+    /// Cancelling the token cancels the write.
+    /// </summary>
+    public Task<int> WriteAsync(T[] payload, CancellationToken cancellationToken = default)
+    {
+        return AsyncSupport.WithCancellation(Write(payload), cancellationToken);
+    }
+
+    /// <summary>
+    /// Writes all of <paramref name="payload"/>, issuing further writes while the reader
+    /// only takes part of it.
+    /// </summary>
+    public async Task WriteAllAsync(T[] payload, CancellationToken cancellationToken = default)
+    {
+        var written = 0;
+        while (written < payload.Length)
+        {
+            written += await WriteAsync(written == 0 ? payload : payload[written..], cancellationToken);
+        }
+    }
+
+    /// <summary>
+    /// Writes every element of <paramref name="source"/>. Elements which are already
+    /// available are batched into a single write. The writer is not disposed.
+    /// </summary>
+    public async Task WriteAllAsync(IAsyncEnumerable<T> source, CancellationToken cancellationToken = default)
+    {
+        var batch = new List<T>();
+        await using var enumerator = source.GetAsyncEnumerator(cancellationToken);
+        while (true)
+        {
+            var next = enumerator.MoveNextAsync();
+            // Flush what we have rather than holding it back while the source waits.
+            if ((!next.IsCompleted && batch.Count > 0) || batch.Count >= BatchSize)
+            {
+                await WriteAllAsync(batch.ToArray(), cancellationToken);
+                batch.Clear();
+            }
+
+            if (!await next)
+            {
+                break;
+            }
+            batch.Add(enumerator.Current);
+        }
+
+        if (batch.Count > 0)
+        {
+            await WriteAllAsync(batch.ToArray(), cancellationToken);
+        }
+    }
+
+    /// <summary>
+    /// Writes every element of <paramref name="source"/> until the channel is completed,
+    /// batching elements which are already available. The writer is not disposed.
+    /// </summary>
+    public async Task WriteAllAsync(global::System.Threading.Channels.ChannelReader<T> source, CancellationToken cancellationToken = default)
+    {
+        var batch = new List<T>();
+        while (await source.WaitToReadAsync(cancellationToken))
+        {
+            while (batch.Count < BatchSize && source.TryRead(out var item))
+            {
+                batch.Add(item);
+            }
+
+            if (batch.Count > 0)
+            {
+                await WriteAllAsync(batch.ToArray(), cancellationToken);
+                batch.Clear();
+            }
+        }
+    }
+
     internal override uint VTableWrite(IntPtr bufferPtr, int length)
     {
         return VTable.Write(Handle, bufferPtr, (uint)length);
@@ -961,9 +1200,17 @@ internal struct WaitableInfoState
         FutureStream = futureStream;        
     }
 
-    internal WaitableInfoState(TaskCompletionSource<int> taskCompletionSource)
+    internal WaitableInfoState(TaskCompletionSource<int> taskCompletionSource, Action? onStarted = null)
     {
         this.taskCompletionSource = taskCompletionSource;
+        this.onStarted = onStarted;
+    }
+
+    // Runs the subtask's start hook at most once.
+    internal void Started()
+    {
+        onStarted?.Invoke();
+        onStarted = null;
     }
 
     internal void SetResult(int count)
@@ -1002,6 +1249,7 @@ internal struct WaitableInfoState
     private TaskCompletionSource<int>? taskCompletionSource;
     private ComponentTask? componentTask;
     private ComponentTask<int>? componentTaskInt;
+    private Action? onStarted;
     internal IFutureStream? FutureStream;
 }
 
@@ -1030,6 +1278,10 @@ public abstract class ComponentTask
 
     public abstract bool IsCompleted { get; }
 
+    /// <summary>
+    /// Cancels the operation. If it transferred data before the cancellation took effect
+    /// the task completes with that data, otherwise it is cancelled.
+    /// </summary>
     public CancelCode Cancel()
     {
         if(!canCancel)
@@ -1042,13 +1294,28 @@ public abstract class ComponentTask
             throw new InvalidOperationException("Cannot cancel a task that was created as completed with a result.");
         }
 
-        uint cancelReturn = cancelableVTable.Cancel();
-        SetCanceled();
-        return (CancelCode)cancelReturn;
+        canCancel = false;
+        var status = new WaitableStatus(cancelableVTable.Cancel());
+        // A task wrapping another one is completed through it.
+        if (!IsCompleted)
+        {
+            if (status.IsCompleted || status.Count > 0)
+            {
+                SetCancelledResult((int)status.Count);
+            }
+            else
+            {
+                SetCanceled();
+            }
+        }
+        return (CancelCode)status.State;
     }
 
     public abstract void SetCanceled();
 
+    // Completes a read or write with the number of elements transferred before it was cancelled.
+    internal abstract void SetCancelledResult(int count);
+
     public virtual void SetResult()
     {
         canCancel = false;
@@ -1108,6 +1375,12 @@ public class ComponentTask<T> : ComponentTask
         tcs.SetCanceled();
     }
 
+    internal override void SetCancelledResult(int count)
+    {
+        // Only the tasks counting the elements of a read or write cancel an operation themselves.
+        SetResult((T)(object)count);
+    }
+
     public override void SetException(Exception e)
     {
         tcs.SetException(e);
@@ -1,8 +1,10 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use std::{fs, path::PathBuf};
 
 use heck::ToUpperCamelCase;
 
+use crate::{CSharpRuntime, Opts};
+
 pub struct CSProject;
 
 pub struct CSProjectLLVMBuilder {
@@ -102,8 +104,9 @@ impl CSProjectLLVMBuilder {
         if self.aot {
             let os = match std::env::consts::OS {
                 "windows" => "win",
-                "linux" => std::env::consts::OS,
-                other => todo!("OS {} not supported", other),
+                "macos" => "osx",
+                "linux" => "linux",
+                other => bail!("NativeAOT-LLVM has no ILCompiler package for `{other}`"),
             };
 
             // Share nuget packages between codegen and runtime tests.
@@ -282,3 +285,97 @@ impl CSProjectMonoBuilder {
         self
     }
 }
+
+/// Generates `WitBindgen.props`, which declares the overridable properties
+/// and the `Wit` item type used by `WitBindgen.targets`.
+pub(crate) fn msbuild_props(opts: &Opts, world_name: &str) -> String {
+    let runtime = match opts.runtime {
+        CSharpRuntime::NativeAOT => "native-aot",
+        CSharpRuntime::Mono => "mono",
+    };
+
+    let mut args = vec![format!("--string-encoding {}", opts.string_encoding)];
+    if opts.internal {
+        args.push("--internal".to_string());
+    }
+    if opts.with_wit_results {
+        args.push("--with-wit-results".to_string());
+    }
+    if opts.nullable {
+        args.push("--nullable".to_string());
+    }
+    if opts.skip_support_files {
+        args.push("--skip-support-files".to_string());
+    }
+    let args = args.join(" ");
+
+    format!(
+        r#"<Project>
+  <!-- Generated by `wit-bindgen` {version}. DO NOT EDIT! -->
+  <PropertyGroup>
+    <WitBindgenPath Condition="'$(WitBindgenPath)' == ''">wit-bindgen</WitBindgenPath>
+    <WitBindgenRuntime Condition="'$(WitBindgenRuntime)' == ''">{runtime}</WitBindgenRuntime>
+    <WitBindgenWorld Condition="'$(WitBindgenWorld)' == ''">{world_name}</WitBindgenWorld>
+    <WitBindgenArgs Condition="'$(WitBindgenArgs)' == ''">{args}</WitBindgenArgs>
+  </PropertyGroup>
+
+  <ItemDefinitionGroup>
+    <Wit>
+      <World>$(WitBindgenWorld)</World>
+    </Wit>
+  </ItemDefinitionGroup>
+</Project>
+"#,
+        version = env!("CARGO_PKG_VERSION"),
+    )
+}
+
+/// Generates `WitBindgen.targets`, which regenerates bindings for each `Wit`
+/// item when it changes and passes the resulting component type to the
+/// linker.
+pub(crate) fn msbuild_targets() -> String {
+    format!(
+        r#"<Project>
+  <!-- Generated by `wit-bindgen` {version}. DO NOT EDIT! -->
+  <PropertyGroup>
+    <WitBindgenOutputDir Condition="'$(WitBindgenOutputDir)' == ''">$(IntermediateOutputPath)wit-bindgen/</WitBindgenOutputDir>
+    <WitBindgenStamp>$(WitBindgenOutputDir)wit-bindgen.stamp</WitBindgenStamp>
+  </PropertyGroup>
+
+  <Target Name="WitBindgenGenerate"
+          Condition="'@(Wit)' != ''"
+          Inputs="@(Wit);$(MSBuildAllProjects)"
+          Outputs="$(WitBindgenStamp)">
+    <ItemGroup>
+      <_WitBindgenInput Include="@(Wit)">
+        <Package Condition="'%(Wit.Package)' == ''">%(Wit.FullPath)</Package>
+      </_WitBindgenInput>
+    </ItemGroup>
+
+    <RemoveDir Directories="$(WitBindgenOutputDir)" />
+    <Exec Command="&quot;$(WitBindgenPath)&quot; csharp &quot;%(_WitBindgenInput.Package)&quot; --world &quot;%(_WitBindgenInput.World)&quot; --runtime $(WitBindgenRuntime) $(WitBindgenArgs) --out-dir &quot;$(WitBindgenOutputDir)%(_WitBindgenInput.World)&quot;" />
+    <Touch Files="$(WitBindgenStamp)" AlwaysCreate="true" />
+  </Target>
+
+  <Target Name="WitBindgenAddGenerated"
+          DependsOnTargets="WitBindgenGenerate"
+          BeforeTargets="CoreCompile"
+          Condition="'@(Wit)' != ''">
+    <ItemGroup>
+      <_WitBindgenSource Include="$(WitBindgenOutputDir)**/*.cs" />
+      <Compile Include="@(_WitBindgenSource)" Exclude="@(Compile)" />
+      <_WitBindgenComponentType Include="$(WitBindgenOutputDir)**/*_component_type.wit" />
+      <_WitBindgenComponentObject Include="$(WitBindgenOutputDir)**/*_component_type.o" />
+      <FileWrites Include="@(_WitBindgenSource);@(_WitBindgenComponentType);@(_WitBindgenComponentObject);$(WitBindgenStamp)" />
+
+      <CustomLinkerArg Condition="'$(WitBindgenRuntime)' == 'native-aot'"
+                       Include="@(_WitBindgenComponentType->'-Wl,--component-type,&quot;%(FullPath)&quot;')" />
+      <NativeFileReference Condition="'$(WitBindgenRuntime)' == 'mono'"
+                           Include="@(_WitBindgenComponentObject)" />
+    </ItemGroup>
+  </Target>
+</Project>
+"#,
+        version = env!("CARGO_PKG_VERSION"),
+    )
+}
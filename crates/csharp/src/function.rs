@@ -22,7 +22,7 @@ pub(crate) struct FunctionBindgen<'a, 'b> {
     params: Box<[String]>,
     results: Vec<TypeId>,
     pub(crate) src: String,
-    locals: Ns,
+    pub(crate) locals: Ns,
     block_storage: Vec<BlockStorage>,
     blocks: Vec<Block>,
     payloads: Vec<String>,
@@ -35,6 +35,10 @@ pub(crate) struct FunctionBindgen<'a, 'b> {
     parameter_type: ParameterType,
     pub(crate) result_type: Option<Type>,
     pub(crate) resource_type_name: Option<String>,
+    /// Set while lowering the arguments of an async import. The callee may read
+    /// them after the import call returns, so nothing is stack allocated and
+    /// every allocation or pin is released through `cleanups` instead.
+    pub(crate) async_import_params: bool,
 }
 
 impl<'a, 'b> FunctionBindgen<'a, 'b> {
@@ -73,6 +77,7 @@ impl<'a, 'b> FunctionBindgen<'a, 'b> {
             parameter_type: parameter_type,
             result_type: result_type,
             resource_type_name: None,
+            async_import_params: false,
         }
     }
 
@@ -389,47 +394,6 @@ impl<'a, 'b> FunctionBindgen<'a, 'b> {
         }
         ret
     }
-
-    pub fn emit_allocation_for_type(&mut self, results: &[WasmType]) -> String {
-        let address = self.locals.tmp("address");
-        let buffer_size = self.get_size_for_type(results);
-        let align = self.get_align_for_type(results);
-        uwriteln!(
-            self.src,
-            "void* {address} = global::System.Runtime.InteropServices.NativeMemory.AlignedAlloc({buffer_size}, {align});"
-        );
-
-        // TODO: Store the address somewhere so we can free it when the task completes.
-        address
-    }
-
-    fn get_size_for_type(&self, results: &[WasmType]) -> usize {
-        match results {
-            [WasmType::I32] => 4,
-            [WasmType::I64] => 8,
-            [WasmType::F32] => 4,
-            [WasmType::F64] => 8,
-            [WasmType::Pointer, WasmType::Length] => 4, // TODO: Wasm64
-            [WasmType::PointerOrI64, WasmType::Length] => 8,
-            _ => {
-                todo!("other types not yet supported");
-            }
-        }
-    }
-
-    fn get_align_for_type(&self, results: &[WasmType]) -> usize {
-        match results {
-            [WasmType::I32] => 4,
-            [WasmType::I64] => 8,
-            [WasmType::F32] => 4,
-            [WasmType::F64] => 8,
-            [WasmType::Pointer, WasmType::Length] => 4, // TODO: Wasm64
-            [WasmType::PointerOrI64, WasmType::Length] => 8,
-            _ => {
-                todo!("other types not yet supported");
-            }
-        }
-    }
 }
 
 impl Bindgen for FunctionBindgen<'_, '_> {
@@ -872,7 +836,22 @@ impl Bindgen for FunctionBindgen<'_, '_> {
                         let ptr: String = self.locals.tmp("listPtr");
                         let handle: String = self.locals.tmp("gcHandle");
 
-                        if !self.is_block && self.parameter_type == ParameterType::Span {
+                        if !self.is_block
+                            && self.async_import_params
+                            && self.parameter_type == ParameterType::Memory
+                        {
+                            // A `fixed` statement only pins for the duration of the
+                            // call, so pin the memory until the subtask has started.
+                            self.needs_cleanup = true;
+                            uwrite!(
+                                self.src,
+                                "
+                                var {handle} = {list}.Pin();
+                                var {ptr} = (nint){handle}.Pointer;
+                                cleanups.Add(() => {handle}.Dispose());
+                                "
+                            );
+                        } else if !self.is_block && self.parameter_type == ParameterType::Span {
                             self.fixed_statments.push(Fixed {
                                 item_to_pin: list.clone(),
                                 ptr_name: ptr.clone(),
@@ -984,6 +963,13 @@ impl Bindgen for FunctionBindgen<'_, '_> {
                         global::System.Text.Encoding.UTF8.GetBytes({string_span}, new global::System.Span<byte>({str_ptr}, {length}));
                         "
                     );
+                    if self.async_import_params {
+                        self.needs_cleanup = true;
+                        uwriteln!(
+                            self.src,
+                            "cleanups.Add(() => global::System.Runtime.InteropServices.NativeMemory.Free({str_ptr}));"
+                        );
+                    }
                     results.push(format!("(int){str_ptr}"));
                 }
 
@@ -1053,6 +1039,17 @@ impl Bindgen for FunctionBindgen<'_, '_> {
                 };
 
                 match realloc {
+                    _ if self.async_import_params => {
+                        self.needs_cleanup = true;
+                        uwrite!(
+                            self.src,
+                            "
+                            var {buffer_size} = {size} * (nuint){list}.Count;
+                            void* {address} = global::System.Runtime.InteropServices.NativeMemory.AlignedAlloc({buffer_size}, {align});
+                            cleanups.Add(() => global::System.Runtime.InteropServices.NativeMemory.AlignedFree({address}));
+                            "
+                        );
+                    }
                     None => {
                         self.needs_cleanup = true;
                         self.interface_gen.csharp_gen.needs_align_stack_ptr = true;
@@ -1800,6 +1797,17 @@ impl Bindgen for FunctionBindgen<'_, '_> {
                 };
 
                 match realloc {
+                    _ if self.async_import_params => {
+                        self.needs_cleanup = true;
+                        uwrite!(
+                            self.src,
+                            "
+                            var {buffer_size} = {size} * (nuint){map}.Count;
+                            void* {address} = global::System.Runtime.InteropServices.NativeMemory.AlignedAlloc({buffer_size}, {align});
+                            cleanups.Add(() => global::System.Runtime.InteropServices.NativeMemory.AlignedFree({address}));
+                            "
+                        );
+                    }
                     None => {
                         self.needs_cleanup = true;
                         self.interface_gen.csharp_gen.needs_align_stack_ptr = true;
@@ -1938,13 +1946,130 @@ impl Bindgen for FunctionBindgen<'_, '_> {
                 );
             }
 
+            Instruction::FixedLengthListLower { size, .. } => {
+                let array = self.locals.tmp("array");
+                uwriteln!(self.src, "var {array} = {};", operands[0]);
+                for i in 0..*size {
+                    results.push(format!("{array}[{i}]"));
+                }
+            }
+
+            Instruction::FixedLengthListLift { id, .. } => {
+                let ty = self
+                    .interface_gen
+                    .type_name_with_qualifier(&Type::Id(*id), true);
+                let array = self.locals.tmp("array");
+                uwriteln!(self.src, "var {array} = new {ty}();");
+                for (i, op) in operands.iter().enumerate() {
+                    uwriteln!(self.src, "{array}[{i}] = {op};");
+                }
+                results.push(array);
+            }
+
+            Instruction::FixedLengthListLowerToMemory {
+                element,
+                size,
+                offset,
+                ..
+            } => {
+                let block = self.blocks.pop().unwrap();
+                assert!(block.results.is_empty());
+
+                let ty = self.interface_gen.type_name_with_qualifier(element, true);
+                let array = self.locals.tmp("array");
+                let address = &operands[1];
+                uwriteln!(self.src, "var {array} = {};", operands[0]);
+
+                if crate::world_generator::is_primitive(element) {
+                    let offset = offset.size_wasm32();
+                    uwriteln!(
+                        self.src,
+                        "((global::System.ReadOnlySpan<{ty}>){array}).CopyTo(new global::System.Span<{ty}>((void*)((byte*){address} + {offset}), {size}));"
+                    );
+                } else {
+                    let Block {
+                        body,
+                        element: block_element,
+                        base,
+                        ..
+                    } = block;
+                    let elem_size = self
+                        .interface_gen
+                        .csharp_gen
+                        .sizes
+                        .size(element)
+                        .size_wasm32();
+                    let index = self.locals.tmp("index");
+                    uwrite!(
+                        self.src,
+                        "
+                        for (int {index} = 0; {index} < {size}; ++{index}) {{
+                            {ty} {block_element} = {array}[{index}];
+                            nint {base} = (nint)({address}) + ({index} * {elem_size});
+                            {body}
+                        }}
+                        "
+                    );
+                }
+            }
+
+            Instruction::FixedLengthListLiftFromMemory {
+                element,
+                size,
+                id,
+                offset,
+            } => {
+                let block = self.blocks.pop().unwrap();
+                let ty = self
+                    .interface_gen
+                    .type_name_with_qualifier(&Type::Id(*id), true);
+                let array = self.locals.tmp("array");
+                let address = &operands[0];
+                uwriteln!(self.src, "var {array} = new {ty}();");
+
+                if crate::world_generator::is_primitive(element) {
+                    let offset = offset.size_wasm32();
+                    let element_ty = self.interface_gen.type_name_with_qualifier(element, true);
+                    uwriteln!(
+                        self.src,
+                        "new global::System.ReadOnlySpan<{element_ty}>((void*)((byte*){address} + {offset}), {size}).CopyTo({array});"
+                    );
+                } else {
+                    let Block {
+                        body,
+                        results: block_results,
+                        base,
+                        ..
+                    } = block;
+                    let result = match &block_results[..] {
+                        [result] => result,
+                        _ => todo!("result count == {}", block_results.len()),
+                    };
+                    let elem_size = self
+                        .interface_gen
+                        .csharp_gen
+                        .sizes
+                        .size(element)
+                        .size_wasm32();
+                    let index = self.locals.tmp("index");
+                    uwrite!(
+                        self.src,
+                        "
+                        for (int {index} = 0; {index} < {size}; ++{index}) {{
+                            nint {base} = (nint)({address}) + ({index} * {elem_size});
+                            {body}
+                            {array}[{index}] = {result};
+                        }}
+                        "
+                    );
+                }
+
+                results.push(array);
+            }
+
             Instruction::ErrorContextLower { .. }
             | Instruction::ErrorContextLift { .. }
-            | Instruction::DropHandle { .. }
-            | Instruction::FixedLengthListLift { .. }
-            | Instruction::FixedLengthListLower { .. }
-            | Instruction::FixedLengthListLowerToMemory { .. }
-            | Instruction::FixedLengthListLiftFromMemory { .. } => {
+            | Instruction::DropHandle { .. } => {
                 dbg!(inst);
                 todo!()
             }
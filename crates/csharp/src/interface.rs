@@ -1,6 +1,7 @@
 use crate::csharp_ident::ToCSharpIdent;
 use crate::function::FunctionBindgen;
 use crate::function::ResourceInfo;
+use crate::function::dealias;
 use crate::world_generator::CSharp;
 use heck::ToLowerCamelCase;
 use heck::{ToShoutySnakeCase, ToUpperCamelCase};
@@ -138,6 +139,9 @@ impl InterfaceGenerator<'_> {
             TypeDefKind::Option(t) => self.type_option(type_id, typedef_name, t, &type_def.docs),
             TypeDefKind::Record(t) => self.type_record(type_id, typedef_name, t, &type_def.docs),
             TypeDefKind::List(t) => self.type_list(type_id, typedef_name, t, &type_def.docs),
+            TypeDefKind::FixedLengthList(t, size) => {
+                self.type_fixed_length_list(type_id, typedef_name, t, *size, &type_def.docs)
+            }
             TypeDefKind::Map(key, value) => {
                 self.type_map(type_id, typedef_name, key, value, &type_def.docs)
             }
@@ -504,60 +508,46 @@ impl InterfaceGenerator<'_> {
 
         let interop_camel_name = func.item_name().to_upper_camel_case();
 
-        let sig = self.resolve.wasm_signature(AbiVariant::GuestImport, func);
+        let is_async = InterfaceGenerator::is_async(&func.kind);
 
-        let is_async = matches!(
-            func.kind,
-            FunctionKind::AsyncFreestanding
-                | FunctionKind::AsyncStatic(_)
-                | FunctionKind::AsyncMethod(_)
+        let sig = self.resolve.wasm_signature(
+            if is_async {
+                AbiVariant::GuestImportAsync
+            } else {
+                AbiVariant::GuestImport
+            },
+            func,
         );
 
         let mut wasm_result_type = match &sig.results[..] {
-            [] => {
-                if is_async {
-                    "uint"
-                } else {
-                    "void"
-                }
-            }
+            [] => "void",
             [result] => crate::world_generator::wasm_type(*result),
             _ => unreachable!(),
         };
 
         let (result_type, results) = self.func_payload_and_return_type(func);
 
-        let is_async = InterfaceGenerator::is_async(&func.kind);
-
-        let requires_async_return_buffer_param = is_async && !sig.results.is_empty();
+        let requires_async_return_buffer_param = is_async && sig.retptr;
         let sig_unsafe = if requires_async_return_buffer_param {
             "unsafe "
         } else {
             ""
         };
 
-        let wasm_params: String = {
-            let param_list = sig
-                .params
-                .iter()
-                .enumerate()
-                .map(|(i, param)| {
-                    let ty = crate::world_generator::wasm_type(*param);
-                    format!("{ty} p{i}")
-                })
-                .collect::<Vec<_>>()
-                .join(", ");
-
-            if requires_async_return_buffer_param {
-                if param_list.is_empty() {
+        let wasm_params: String = sig
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                if requires_async_return_buffer_param && i == sig.params.len() - 1 {
                     "nint taskResultBuffer".to_string()
                 } else {
-                    format!("{param_list}, nint taskResultBuffer")
+                    let ty = crate::world_generator::wasm_type(*param);
+                    format!("{ty} p{i}")
                 }
-            } else {
-                param_list
-            }
-        };
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
 
         let mut funcs: Vec<(String, String)> = Vec::new();
         funcs.push(self.gen_import_src(func, &results, ParameterType::ABI));
@@ -573,7 +563,11 @@ impl InterfaceGenerator<'_> {
             .any(|param| self.is_primative_list(&param.ty));
 
         if include_additional_functions {
-            funcs.push(self.gen_import_src(func, &results, ParameterType::Span));
+            // A span can't be pinned beyond the call, which an async import's
+            // arguments must outlive.
+            if !is_async {
+                funcs.push(self.gen_import_src(func, &results, ParameterType::Span));
+            }
             funcs.push(self.gen_import_src(func, &results, ParameterType::Memory));
         }
 
@@ -713,17 +707,20 @@ impl InterfaceGenerator<'_> {
 
             // TODO: the result is a tmp so could be result/1/2/..  Maybe this will fallout if we use lower_to_memory.
             let async_status_var = "result"; // String::new();
-            let requires_async_return_buffer_param = func.result.is_some();
-            let async_return_buffer = if requires_async_return_buffer_param {
-                let buffer = bindgen.emit_allocation_for_type(&sig.results);
+            // The callee writes its results into this buffer once it returns, so it
+            // is only freed after they have been lifted.
+            let async_return_buffer = func.result.map(|ty| {
+                let buffer = bindgen.locals.tmp("address");
+                let sizes = &bindgen.interface_gen.csharp_gen.sizes;
+                let size = sizes.size(&ty).size_wasm32();
+                let align = sizes.align(&ty).align_wasm32();
                 uwriteln!(
                     bindgen.src,
-                    "//TODO: store somewhere with the TaskCompletionSource, possibly in the state, using Task.AsyncState to retrieve it later."
+                    "void* {buffer} = global::System.Runtime.InteropServices.NativeMemory.AlignedAlloc({size}, {align});"
                 );
-                Some(buffer)
-            } else {
-                None
-            };
+                buffer
+            });
+            bindgen.async_import_params = true;
 
             let csharp_param_names = func
                 .params
@@ -731,8 +728,41 @@ impl InterfaceGenerator<'_> {
                 .map(|param| param.name.to_lower_camel_case())
                 .collect::<Vec<_>>();
 
-            let (lower, wasm_params) = if sig.indirect_params {
-                todo!("indirect params not supported for async imports yet");
+            // Too many flat params: spill them into a heap buffer. The buffer, like
+            // any pinned arguments, must stay alive until the callee has read them,
+            // i.e. until the subtask has started.
+            let mut params_buffer = None;
+            let (mut lower, wasm_params) = if sig.indirect_params {
+                let params = bindgen.locals.tmp("spilledParams");
+                let abi = bindgen
+                    .interface_gen
+                    .csharp_gen
+                    .sizes
+                    .record(func.params.iter().map(|param| &param.ty));
+                let size = abi.size.size_wasm32();
+                let align = abi.align.align_wasm32();
+                let offsets = bindgen
+                    .interface_gen
+                    .csharp_gen
+                    .sizes
+                    .field_offsets(func.params.iter().map(|param| &param.ty));
+                uwriteln!(
+                    bindgen.src,
+                    "void* {params} = global::System.Runtime.InteropServices.NativeMemory.AlignedAlloc({size}, {align});"
+                );
+                for (name, (offset, ty)) in csharp_param_names.iter().zip(offsets) {
+                    let offset = offset.size_wasm32();
+                    abi::lower_to_memory(
+                        bindgen.interface_gen.resolve,
+                        &mut bindgen,
+                        format!("((byte*){params} + {offset})"),
+                        name.clone(),
+                        ty,
+                    );
+                }
+                let wasm_params = vec![format!("(nint){params}")];
+                params_buffer = Some(params);
+                (mem::take(&mut bindgen.src), wasm_params)
             } else {
                 let wasm_params: Vec<String> = csharp_param_names
                     .iter()
@@ -765,6 +795,36 @@ impl InterfaceGenerator<'_> {
 
             let wasm_params = wasm_param_refs.join(", ");
 
+            let mut on_started = String::new();
+            if let Some(params) = &params_buffer {
+                uwriteln!(
+                    on_started,
+                    "global::System.Runtime.InteropServices.NativeMemory.AlignedFree({params});"
+                );
+            }
+            if mem::take(&mut bindgen.needs_cleanup) {
+                lower.insert_str(
+                    0,
+                    "var cleanups = new global::System.Collections.Generic.List<global::System.Action>();\n",
+                );
+                uwriteln!(
+                    on_started,
+                    "foreach (var cleanup in cleanups)
+                    {{
+                        cleanup();
+                    }}"
+                );
+            }
+            let on_started = if on_started.is_empty() {
+                on_started
+            } else {
+                format!(
+                    ", () => {{
+                        {on_started}
+                    }}"
+                )
+            };
+
             // TODO: lift expr
             let code = format!(
                 "{lower}
@@ -777,34 +837,36 @@ var {async_status_var} = {raw_name}({wasm_params});
             let bindgen_src = bindgen.src;
             if let Some(buffer) = async_return_buffer {
                 let ty = bindgen.result_type.expect("expected a result type");
-                let (lift_expr, res) = self.lift_from_memory("address", &ty);
+                let (lift_expr, res) = self.lift_from_memory(&buffer, &ty);
                 uwriteln!(src, "{}", bindgen_src);
                 let return_type = self.type_name_with_qualifier(&ty, true);
 
                 let lift_func = format!(
                     "() => {{
-                        {lift_expr}
+                        try
+                        {{
+                            {lift_expr}
 
-                        return {res};
+                            return {res};
+                        }}
+                        finally
+                        {{
+                            global::System.Runtime.InteropServices.NativeMemory.AlignedFree({buffer});
+                        }}
                     }}"
                 );
                 uwriteln!(
                     src,
                     "
-                    var task = AsyncSupport.TaskFromStatus<{return_type}>({async_status_var}, {});",
+                    var task = AsyncSupport.TaskFromStatus<{return_type}>({async_status_var}, {}{on_started});",
                     lift_func
                 );
-                uwriteln!(
-                    src,
-                    "global::System.Runtime.InteropServices.NativeMemory.Free({});",
-                    buffer
-                );
                 uwriteln!(src, "return task;");
             } else {
                 uwriteln!(
                     src,
                     "
-                    return AsyncSupport.TaskFromStatus({async_status_var});"
+                    return AsyncSupport.TaskFromStatus({async_status_var}{on_started});"
                 );
             }
         } else {
@@ -828,7 +890,7 @@ var {async_status_var} = {raw_name}({wasm_params});
                 0
             })
             .map(|param| {
-                let ty = self.name_with_qualifier(&param.ty, true, parameter_type);
+                let ty = self.param_name_with_qualifier(&param.ty, parameter_type);
                 let param_name = &param.name;
                 let param_name = param_name.to_csharp_ident();
                 format!("{ty} {param_name}")
@@ -1157,6 +1219,7 @@ var {async_status_var} = {raw_name}({wasm_params});
                     TypeDefKind::Option(_ty) => "".to_owned(),
                     TypeDefKind::Result(_result) => "".to_owned(),
                     TypeDefKind::List(_list) => "".to_owned(),
+                    TypeDefKind::FixedLengthList(..) => "".to_owned(),
                     TypeDefKind::Tuple(_tuple) => "".to_owned(),
                     TypeDefKind::Type(inner_type) => self.global_if_user_type(inner_type),
                     _ => "global::".to_owned(),
@@ -1170,6 +1233,43 @@ var {async_status_var} = {raw_name}({wasm_params});
         self.name_with_qualifier(ty, qualifier, ParameterType::ABI)
     }
 
+    /// Like `name_with_qualifier`, but top-level maps are only borrowed by
+    /// an import call, so they are accepted as `IReadOnlyDictionary`.
+    fn param_name_with_qualifier(&mut self, ty: &Type, parameter_type: ParameterType) -> String {
+        if let Type::Id(id) = ty {
+            match &self.resolve.types[*id].kind {
+                TypeDefKind::Type(ty) => {
+                    return self.param_name_with_qualifier(ty, parameter_type);
+                }
+                TypeDefKind::Map(key, value) => {
+                    return format!(
+                        "global::System.Collections.Generic.IReadOnlyDictionary<{}, {}>",
+                        self.type_name_with_qualifier(key, true),
+                        self.type_name_with_qualifier(value, true)
+                    );
+                }
+                _ => {}
+            }
+        }
+        self.name_with_qualifier(ty, true, parameter_type)
+    }
+
+    /// Whether `ty` is emitted as a nullable `T?`, i.e. an `option<T>` whose
+    /// payload isn't itself an option. Nested options are non-nullable
+    /// `Option<T>` wrappers.
+    fn is_nullable(&self, ty: &Type) -> bool {
+        let Type::Id(id) = ty else {
+            return false;
+        };
+        match &self.resolve.types[dealias(self.resolve, *id)].kind {
+            TypeDefKind::Option(Type::Id(payload)) => {
+                !matches!(self.resolve.types[*payload].kind, TypeDefKind::Option(_))
+            }
+            TypeDefKind::Option(_) => true,
+            _ => false,
+        }
+    }
+
     fn is_primative_list(&mut self, ty: &Type) -> bool {
         match ty {
             Type::Id(id) => {
@@ -1233,6 +1333,13 @@ var {async_status_var} = {raw_name}({wasm_params});
                             )
                         }
                     }
+                    TypeDefKind::FixedLengthList(ty, size) => {
+                        self.csharp_gen.fixed_length_list_sizes.insert(*size);
+                        format!(
+                            "FixedLengthList{size}<{}>",
+                            self.type_name_with_qualifier(ty, qualifier)
+                        )
+                    }
                     TypeDefKind::Tuple(tuple) => {
                         let count = tuple.types.len();
                         self.csharp_gen.tuple_counts.insert(count);
@@ -1692,8 +1799,27 @@ impl<'a> CoreInterfaceGenerator<'a> for InterfaceGenerator<'a> {
             .collect::<Vec<_>>()
             .join("\n");
 
+        let nullable = self.csharp_gen.opts.nullable;
         let fields = if record.fields.is_empty() {
             format!("{access} const {name} INSTANCE = new {name}();")
+        } else if nullable {
+            record
+                .fields
+                .iter()
+                .map(|field| {
+                    let required = if self.is_nullable(&field.ty) {
+                        ""
+                    } else {
+                        "required "
+                    };
+                    format!(
+                        "{access} {required}{} {} {{ get; init; }}",
+                        self.type_name(&field.ty),
+                        field.name.to_csharp_ident()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
         } else {
             record
                 .fields
@@ -1709,12 +1835,20 @@ impl<'a> CoreInterfaceGenerator<'a> for InterfaceGenerator<'a> {
                 .join("\n")
         };
 
+        // The constructor initializes every member, including the `required` ones.
+        let sets_required = if nullable && !record.fields.is_empty() {
+            "[global::System.Diagnostics.CodeAnalysis.SetsRequiredMembers]"
+        } else {
+            ""
+        };
+
         uwrite!(
             self.src,
             "
             {access} struct {name} {{
                 {fields}
 
+                {sets_required}
                 {access} {name}({parameters}) {{
                     {assignments}
                 }}
@@ -1917,13 +2051,13 @@ impl<'a> CoreInterfaceGenerator<'a> for InterfaceGenerator<'a> {
 
     fn type_fixed_length_list(
         &mut self,
-        _id: TypeId,
+        id: TypeId,
         _name: &str,
         _ty: &Type,
         _size: u32,
         _docs: &Docs,
     ) {
-        todo!("named fixed-length list types are not yet supported in the C# backend")
+        self.type_name(&Type::Id(id));
     }
 
     fn type_map(&mut self, id: TypeId, _name: &str, _key: &Type, _value: &Type, _docs: &Docs) {
@@ -34,6 +34,18 @@ pub struct Opts {
     /// Generate code for WIT `Result` types instead of exceptions
     #[cfg_attr(feature = "clap", arg(long))]
     pub with_wit_results: bool,
+
+    /// Generate record fields as `init` properties, marking those which
+    /// aren't nullable `required`, so nullable reference type analysis can
+    /// check record construction
+    #[cfg_attr(feature = "clap", arg(long))]
+    pub nullable: bool,
+
+    /// Also generate `WitBindgen.props` and `WitBindgen.targets` which
+    /// regenerate bindings for `<Wit Include="..." />` items and pass the
+    /// component type to the linker
+    #[cfg_attr(feature = "clap", arg(long))]
+    pub msbuild_targets: bool,
 }
 
 impl Opts {
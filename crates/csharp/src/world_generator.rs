@@ -1,10 +1,11 @@
 use crate::csharp_ident::ToCSharpIdent;
+use crate::csproj;
 use crate::function::ResourceInfo;
 use crate::interface::{InterfaceFragment, InterfaceGenerator, InterfaceTypeAndFragments};
 use crate::{CSharpRuntime, Opts};
 use heck::ToUpperCamelCase;
 use indexmap::IndexMap;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::Write;
 use std::ops::Deref;
 use std::{iter, mem};
@@ -29,6 +30,7 @@ pub struct CSharp {
     pub(crate) return_area_size: usize,
     pub(crate) return_area_align: usize,
     pub(crate) tuple_counts: HashSet<usize>,
+    pub(crate) fixed_length_list_sizes: BTreeSet<u32>,
     pub(crate) needs_result: bool,
     pub(crate) needs_option: bool,
     pub(crate) needs_export_return_area: bool,
@@ -560,6 +562,23 @@ impl WorldGenerator for CSharp {
             )
         }
 
+        // `list<T, N>` maps to an `[InlineArray(N)]` struct rather than a
+        // length-checked `T[]`: the export return area below already relies on
+        // `InlineArray` and generated projects target .NET 10, so no supported
+        // runtime needs the array fallback, which would allocate per value.
+        for size in &self.fixed_length_list_sizes {
+            uwrite!(
+                src,
+                r#"
+
+                [global::System.Runtime.CompilerServices.InlineArray({size})]
+                {access} struct FixedLengthList{size}<T> {{
+                    private T element0;
+                }}
+                "#,
+            )
+        }
+
         if self.needs_wit_exception {
             uwrite!(
                 src,
@@ -860,6 +879,22 @@ impl WorldGenerator for CSharp {
                 );
             }
 
+            if self.opts.runtime == CSharpRuntime::Mono {
+                // The Mono wasi SDK doesn't take linker arguments, so pass the
+                // component type as an object file through `NativeFileReference`
+                // instead.
+                files.push(
+                    &format!("{world_namespace}_component_type.o"),
+                    &wit_bindgen_c::component_type_object::object(
+                        resolve,
+                        id,
+                        &world.name,
+                        self.opts.string_encoding,
+                        None,
+                    )?,
+                );
+            }
+
             // TODO: remove when we switch to dotnet 9
             let mut wasm_import_linakge_src = String::new();
 
@@ -941,6 +976,14 @@ impl WorldGenerator for CSharp {
             }
         }
 
+        if self.opts.msbuild_targets {
+            files.push(
+                "WitBindgen.props",
+                csproj::msbuild_props(&self.opts, &world.name).as_bytes(),
+            );
+            files.push("WitBindgen.targets", csproj::msbuild_targets().as_bytes());
+        }
+
         Ok(())
     }
 }
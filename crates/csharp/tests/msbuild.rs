@@ -0,0 +1,119 @@
+use wit_bindgen_core::{Files, wit_parser::Resolve};
+use wit_bindgen_csharp::{CSharpRuntime, Opts};
+
+fn generate(opts: Opts) -> Files {
+    const WIT: &str = r#"
+        package a:b;
+
+        world my-world {
+          import f: func(x: string) -> u32;
+        }
+    "#;
+
+    let mut resolve = Resolve::default();
+    let package = resolve.push_str("test.wit", WIT).unwrap();
+    let world = resolve.select_world(&[package], Some("my-world")).unwrap();
+    let mut files = Files::default();
+    opts.build()
+        .generate(&mut resolve, world, &mut files)
+        .unwrap();
+    files
+}
+
+fn file(files: &mut Files, name: &str) -> String {
+    String::from_utf8(
+        files
+            .remove(name)
+            .unwrap_or_else(|| panic!("missing {name}")),
+    )
+    .unwrap()
+}
+
+#[test]
+fn msbuild_files_are_only_emitted_on_request() {
+    let files = generate(Opts::default());
+    assert!(
+        files
+            .iter()
+            .all(|(name, _)| !name.starts_with("WitBindgen.")),
+        "unexpected MSBuild files"
+    );
+}
+
+#[test]
+fn msbuild_props_forward_options() {
+    let mut files = generate(Opts {
+        runtime: CSharpRuntime::Mono,
+        internal: true,
+        nullable: true,
+        skip_support_files: true,
+        msbuild_targets: true,
+        ..Opts::default()
+    });
+    let props = file(&mut files, "WitBindgen.props");
+
+    assert!(props.contains(
+        "<WitBindgenRuntime Condition=\"'$(WitBindgenRuntime)' == ''\">mono</WitBindgenRuntime>"
+    ));
+    assert!(props.contains(
+        "<WitBindgenWorld Condition=\"'$(WitBindgenWorld)' == ''\">my-world</WitBindgenWorld>"
+    ));
+    assert!(props.contains(
+        "<WitBindgenArgs Condition=\"'$(WitBindgenArgs)' == ''\">--string-encoding utf8 --internal --nullable --skip-support-files</WitBindgenArgs>"
+    ));
+    assert!(props.contains("<World>$(WitBindgenWorld)</World>"));
+}
+
+#[test]
+fn msbuild_targets_regenerate_and_link_bindings() {
+    let mut files = generate(Opts {
+        msbuild_targets: true,
+        ..Opts::default()
+    });
+    let targets = file(&mut files, "WitBindgen.targets");
+
+    assert!(targets.contains("<Target Name=\"WitBindgenGenerate\""));
+    assert!(targets.contains(
+        "csharp &quot;%(_WitBindgenInput.Package)&quot; --world &quot;%(_WitBindgenInput.World)&quot; --runtime $(WitBindgenRuntime) $(WitBindgenArgs)"
+    ));
+    assert!(targets.contains("BeforeTargets=\"CoreCompile\""));
+    assert!(
+        targets.contains("<CustomLinkerArg Condition=\"'$(WitBindgenRuntime)' == 'native-aot'\"")
+    );
+    assert!(
+        targets.contains("<NativeFileReference Condition=\"'$(WitBindgenRuntime)' == 'mono'\"")
+    );
+
+    // Every element opened in the targets file is closed again.
+    for tag in [
+        "Project",
+        "PropertyGroup",
+        "Target",
+        "ItemGroup",
+        "_WitBindgenInput",
+    ] {
+        let opened = targets.matches(&format!("<{tag}>")).count()
+            + targets.matches(&format!("<{tag} ")).count()
+            + targets.matches(&format!("<{tag}\n")).count();
+        let closed = targets.matches(&format!("</{tag}>")).count();
+        assert_eq!(opened, closed, "unbalanced <{tag}>");
+    }
+}
+
+#[test]
+fn mono_links_component_type_object() {
+    let has_object = |files: &Files| {
+        files
+            .iter()
+            .any(|(name, _)| name.ends_with("_component_type.o"))
+    };
+
+    let files = generate(Opts {
+        runtime: CSharpRuntime::Mono,
+        ..Opts::default()
+    });
+    assert!(has_object(&files), "missing component type object");
+
+    let files = generate(Opts::default());
+    assert!(!has_object(&files), "unexpected component type object");
+}
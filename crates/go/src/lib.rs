@@ -1,12 +1,14 @@
-use anyhow::Result;
+use anyhow::{Context as _, Result};
 use heck::{ToLowerCamelCase as _, ToSnakeCase as _, ToUpperCamelCase as _};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, hash_map};
 use std::fmt;
 use std::fmt::Write as _;
+use std::fs;
 use std::io::{self, Write as _};
 use std::iter;
 use std::mem;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str::FromStr;
 use std::thread;
@@ -33,6 +35,9 @@ const EXPORT_RETURN_AREA: &str = "exportReturnArea";
 const SYNC_EXPORT_PINNER: &str = "syncExportPinner";
 const PINNER: &str = "pinner";
 
+/// The module path of github.com/bytecodealliance/go-pkg
+const REMOTE_PKG: &str = "go.bytecodealliance.org/pkg";
+
 /// Adds the shared package repository prefix to a package name.
 fn remote_pkg(name: &str) -> String {
     let prefix = match name {
@@ -41,7 +46,7 @@ fn remote_pkg(name: &str) -> String {
         "runtime" => "witRuntime",
         _ => unimplemented!(),
     };
-    format!(r#"{prefix} "go.bytecodealliance.org/pkg/wit/{name}""#)
+    format!(r#"{prefix} "{REMOTE_PKG}/wit/{name}""#)
 }
 
 /// The version of github.com/bytecodealliance/go-pkg that's being used
@@ -143,6 +148,63 @@ pub struct Opts {
     #[cfg_attr(feature = "clap", clap(long))]
     pub print_remote_pkg_version: bool,
 
+    /// Copy the support packages used by the bindings from this checkout of
+    /// github.com/bytecodealliance/go-pkg into the output instead of
+    /// importing them from `go.bytecodealliance.org/pkg`.
+    ///
+    /// The checkout isn't inspected, it must be the release the bindings are
+    /// generated for (see `remote_pkg_version`).
+    ///
+    /// Only the packages the bindings import, directly or through each
+    /// other, are copied.
+    #[cfg_attr(feature = "clap", clap(long, value_name = "DIR"))]
+    pub vendor_runtime: Option<PathBuf>,
+
+    /// Directory, relative to the output, that `--vendor-runtime` copies the
+    /// support packages to.
+    ///
+    /// The packages are imported as `<module>/<path>/types` etc., where
+    /// `<module>` is the `--pkg-name` or `wit_component`. Defaults to `wit`.
+    #[cfg_attr(feature = "clap", clap(long, requires = "vendor_runtime"))]
+    pub vendor_runtime_path: Option<String>,
+
+    /// Bind functions returning `result<T, E>` as returning `(T, error)`, or
+    /// just `error` if there's no `T`.
+    ///
+    /// Imports return the `err` case as a `*ResultError[E]`, which is defined
+    /// alongside the interface's types. Exports may return one as well, any
+    /// other error becomes the `err` case only if `E` is `string` (using
+    /// `Error()`) or if there is no `E`. Otherwise an export returning an
+    /// error which doesn't wrap a `*ResultError[E]` panics, trapping the
+    /// component instance.
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub results_as_errors: bool,
+
+    /// Also generate a `<Name>Context` variant of each async import which
+    /// takes a `context.Context` as its first argument and returns an `error`
+    /// in addition to the import's results.
+    ///
+    /// If the context is done before the call returns, the call is cancelled
+    /// via `subtask.cancel` and `ctx.Err()` is returned, handing owned handles
+    /// passed to a callee which never started back.  While a cancellable
+    /// context is live the call is polled between yields to the scheduler.
+    ///
+    /// The cancellation uses the non-blocking `subtask.cancel`, which needs
+    /// the component model async builtins (`-Wcomponent-model-async-builtins`
+    /// in wasmtime).
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub async_context: bool,
+
+    /// Generate `fmt.Stringer` implementations for enums, flags and variants,
+    /// and `encoding/json` support for records, variants, enums and flags.
+    ///
+    /// Enums encode as their case name, flags as an array of flag names,
+    /// variants as a `{"tag": ..., "value": ...}` object and records use their
+    /// WIT field names.  Enums and flags become defined types, rather than
+    /// aliases of their integer representation, to carry these methods.
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub generate_value_methods: bool,
+
     /// When generating Go package names, include the WIT package version even
     /// if only one version of that package is referenced by the specified
     /// world.
@@ -169,6 +231,9 @@ struct InterfaceData {
     need_unsafe: bool,
     need_runtime: bool,
     need_math: bool,
+    need_result_error: bool,
+    need_subtask_context: bool,
+    need_error_context: bool,
 }
 
 impl InterfaceData {
@@ -178,15 +243,21 @@ impl InterfaceData {
         self.need_unsafe |= data.need_unsafe;
         self.need_runtime |= data.need_runtime;
         self.need_math |= data.need_math;
+        self.need_result_error |= data.need_result_error;
+        self.need_subtask_context |= data.need_subtask_context;
+        self.need_error_context |= data.need_error_context;
     }
 
     fn imports(&self) -> String {
         self.imports
             .iter()
-            .map(|s| s.to_string())
-            .chain(self.need_unsafe.then(|| r#""unsafe""#.into()))
-            .chain(self.need_runtime.then(|| r#""runtime""#.into()))
-            .chain(self.need_math.then(|| r#""math""#.into()))
+            .map(|s| s.as_str())
+            .chain(self.need_unsafe.then_some(r#""unsafe""#))
+            .chain(self.need_runtime.then_some(r#""runtime""#))
+            .chain(self.need_math.then_some(r#""math""#))
+            .chain(self.need_result_error.then_some(r#""fmt""#))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
             .collect::<Vec<_>>()
             .join("\n")
     }
@@ -195,9 +266,12 @@ impl InterfaceData {
         Self {
             code,
             imports: generator.imports,
-            need_unsafe: generator.need_unsafe,
-            need_runtime: generator.need_pinner,
+            need_unsafe: generator.need_unsafe || generator.need_error_context,
+            need_runtime: generator.need_pinner || generator.need_error_context,
             need_math: generator.need_math,
+            need_result_error: generator.need_result_error,
+            need_subtask_context: false,
+            need_error_context: generator.need_error_context,
         }
     }
 }
@@ -210,6 +284,9 @@ impl From<InterfaceGenerator<'_>> for InterfaceData {
             need_unsafe: generator.need_unsafe,
             need_runtime: generator.need_runtime,
             need_math: false,
+            need_result_error: false,
+            need_subtask_context: false,
+            need_error_context: false,
         }
     }
 }
@@ -230,6 +307,7 @@ struct Go {
     need_future: bool,
     need_stream: bool,
     need_unsafe: bool,
+    need_error_context: bool,
     interface_names: HashMap<InterfaceId, WorldKey>,
     interfaces: BTreeMap<String, InterfaceData>,
     export_interfaces: BTreeMap<String, InterfaceData>,
@@ -301,6 +379,27 @@ impl Go {
         self.package_for_owner(resolve, owner.as_ref(), id, local, in_import, imports)
     }
 
+    /// Returns the Go type of a tuple with the specified element types.
+    ///
+    /// `witTypes` only provides `Tuple1` through `Tuple16`; wider tuples
+    /// become an anonymous struct with the same `F<index>` fields.
+    fn tuple_type(&mut self, types: &[String], imports: &mut BTreeSet<String>) -> String {
+        let count = types.len();
+        if count > 16 {
+            let fields = types
+                .iter()
+                .enumerate()
+                .map(|(index, ty)| format!("F{index} {ty}"))
+                .collect::<Vec<_>>()
+                .join("; ");
+            format!("struct {{ {fields} }}")
+        } else {
+            imports.insert(remote_pkg("types"));
+            self.tuples.insert(count);
+            format!("witTypes.Tuple{count}[{}]", types.join(", "))
+        }
+    }
+
     fn type_name(
         &mut self,
         resolve: &Resolve,
@@ -368,22 +467,12 @@ impl Go {
                         format!("witTypes.Result[{ok_type}, {err_type}]")
                     }
                     TypeDefKind::Tuple(tuple) => {
-                        imports.insert(remote_pkg("types"));
-                        let count = tuple.types.len();
-                        if count > 16 {
-                            todo!(
-                                "tuples can not have a capacity greater than 16: {:?}",
-                                ty.kind
-                            )
-                        }
-                        self.tuples.insert(count);
                         let types = tuple
                             .types
                             .iter()
                             .map(|ty| self.type_name(resolve, *ty, local, in_import, imports))
-                            .collect::<Vec<_>>()
-                            .join(", ");
-                        format!("witTypes.Tuple{count}[{types}]")
+                            .collect::<Vec<_>>();
+                        self.tuple_type(&types, imports)
                     }
                     TypeDefKind::Future(ty) => {
                         self.need_future = true;
@@ -415,10 +504,14 @@ impl Go {
                         let value = self.type_name(resolve, *value, local, in_import, imports);
                         format!("map[{key}]{value}")
                     }
-                    _ => todo!("{:?}", ty.kind),
+                    TypeDefKind::FixedLengthList(ty, size) => {
+                        let ty = self.type_name(resolve, *ty, local, in_import, imports);
+                        format!("[{size}]{ty}")
+                    }
+                    TypeDefKind::Unknown => unreachable!(),
                 }
             }
-            _ => todo!("{ty:?}"),
+            Type::ErrorContext => "error".into(),
         }
     }
 
@@ -723,7 +816,10 @@ func Lift{upper_kind}{camel}(handle int32) *witTypes.{upper_kind}Reader[{payload
                             self.mangle_name(resolve, *value, local)
                         )
                     }
-                    kind => todo!("{kind:?}"),
+                    TypeDefKind::FixedLengthList(ty, size) => {
+                        format!("array{size}_{}", self.mangle_name(resolve, *ty, local))
+                    }
+                    TypeDefKind::Unknown => unreachable!(),
                 }
             }
         }
@@ -913,7 +1009,17 @@ impl WorldGenerator for Go {
 "
         );
 
+        let vendored = self.opts.vendor_runtime.is_some();
+        if let Some(dir) = self.opts.vendor_runtime.clone() {
+            self.vendor_runtime(&dir, files)?;
+        }
+
         let src = mem::take(&mut self.src);
+        let error_context = if self.need_error_context {
+            ERROR_CONTEXT_SUPPORT
+        } else {
+            ""
+        };
         let align = self.return_area_align.format(POINTER_SIZE_EXPRESSION);
         let size = self.return_area_size.format(POINTER_SIZE_EXPRESSION);
         let imports = self
@@ -926,23 +1032,30 @@ impl WorldGenerator for Go {
             .join("\n");
 
         let (exports_file_path, package_name, main_func) = if self.opts.pkg_name.is_some() {
-            if self.opts.print_remote_pkg_version {
+            if self.opts.print_remote_pkg_version && !vendored {
                 println!("{}", remote_pkg_version());
             }
             // If a module name is specified, the generated files will be used as a library.
             ("wit_exports/wit_exports.go", "wit_exports", "")
         } else {
+            let require = if vendored {
+                String::new()
+            } else {
+                format!(
+                    "
+require (
+    {REMOTE_PKG} {REMOTE_PKG_VERSION}
+)
+"
+                )
+            };
             files.push(
                 "go.mod",
                 format!(
                     r#"module wit_component
 
 go 1.25
-
-require (
-    go.bytecodealliance.org/pkg {REMOTE_PKG_VERSION}
-)
-"#,
+{require}"#,
                 )
                 .as_bytes(),
             );
@@ -975,7 +1088,7 @@ var staticPinner = runtime.Pinner{{}}
 var {EXPORT_RETURN_AREA} = uintptr(witRuntime.Allocate(&staticPinner, {size}, {align}))
 var {SYNC_EXPORT_PINNER} = runtime.Pinner{{}}
 
-{src}
+{src}{error_context}
 
 {main_func}
 "#
@@ -987,6 +1100,113 @@ var {SYNC_EXPORT_PINNER} = runtime.Pinner{{}}
         for (prefix, interfaces) in [("export_", &self.export_interfaces), ("", &self.interfaces)] {
             for (name, data) in interfaces {
                 let imports = data.imports();
+                let result_error = if data.need_result_error {
+                    r#"
+// ResultError is returned in place of the `err` case of a `result`,
+// carrying its payload.
+type ResultError[E any] struct {
+        Value E
+}
+
+func (self *ResultError[E]) Error() string {
+        return fmt.Sprint(self.Value)
+}
+"#
+                } else {
+                    ""
+                };
+                let subtask_context = if data.need_subtask_context {
+                    r#"
+//go:wasmimport $root [waitable-set-new]
+func wasm_waitable_set_new() uint32
+
+//go:wasmimport $root [waitable-set-poll]
+func wasm_waitable_set_poll(set uint32, event unsafe.Pointer) uint32
+
+//go:wasmimport $root [waitable-set-drop]
+func wasm_waitable_set_drop(set uint32)
+
+//go:wasmimport $root [waitable-join]
+func wasm_waitable_join(waitable, set uint32)
+
+//go:wasmimport $root [async-lower][subtask-cancel]
+func wasm_subtask_cancel(subtask uint32) uint32
+
+//go:wasmimport $root [subtask-drop]
+func wasm_subtask_drop(subtask uint32)
+
+const (
+        subtaskReturned       = 2
+        subtaskStartCancelled = 3
+        waitableBlocked       = 0xFFFFFFFF
+)
+
+// Waits for the subtask started by an async import call returning `status`,
+// cancelling it if `ctx` is done first.  Returns the subtask's final state:
+// `subtaskReturned` if its results were written, `subtaskStartCancelled` if it
+// was cancelled before taking its arguments, or another cancelled state.
+//
+// A context which can never be cancelled blocks on the scheduler like any
+// other async import.  Otherwise the subtask is joined to a waitable set of
+// its own which is polled between yields to the scheduler, so its events never
+// reach `witAsync`: the scheduler has no way to stop waiting on a subtask once
+// it has been cancelled.  The cancellation doesn't block either, other
+// goroutines keep running until the callee acknowledges it.
+func wasm_subtask_wait_context(ctx context.Context, status uint32) uint32 {
+        if ctx.Done() == nil {
+                witAsync.SubtaskWait(status)
+                return subtaskReturned
+        }
+
+        subtask := status >> 4
+        status &= 0xF
+        if status == subtaskReturned {
+                return subtaskReturned
+        }
+
+        set := wasm_waitable_set_new()
+        wasm_waitable_join(subtask, set)
+        defer func() {
+                wasm_waitable_join(subtask, 0)
+                wasm_subtask_drop(subtask)
+                wasm_waitable_set_drop(set)
+        }()
+
+        // Only subtask events, with the subtask's status as their payload, are
+        // delivered to `set`.
+        cancelled := false
+        var event [2]uint32
+        for {
+                if wasm_waitable_set_poll(set, unsafe.Pointer(&event)) != 0 {
+                        status = event[1]
+                }
+                // The results area is only released once the subtask reached a
+                // final state, as the callee may still write to it until then.
+                if status >= subtaskReturned {
+                        return status
+                }
+                if !cancelled {
+                        select {
+                        case <-ctx.Done():
+                                cancelled = true
+                                if code := wasm_subtask_cancel(subtask); code != waitableBlocked {
+                                        return code
+                                }
+                        default:
+                        }
+                }
+                witAsync.Yield()
+        }
+}
+"#
+                } else {
+                    ""
+                };
+                let error_context = if data.need_error_context {
+                    ERROR_CONTEXT_SUPPORT
+                } else {
+                    ""
+                };
                 let code = &data.code;
 
                 files.push(
@@ -1001,7 +1221,7 @@ import (
         {imports}
 )
 
-{code}"
+{code}{result_error}{subtask_context}{error_context}"
                         )
                         .as_bytes(),
                     ),
@@ -1022,6 +1242,72 @@ import (
 }
 
 impl Go {
+    /// Copies the support packages imported by the bindings from the go-pkg
+    /// checkout in `dir` into the output and points all imports of them,
+    /// including the ones between the packages, at the copies.
+    fn vendor_runtime(&mut self, dir: &Path, files: &mut Files) -> Result<()> {
+        let path = self.opts.vendor_runtime_path.as_deref().unwrap_or("wit");
+        let module = self.opts.pkg_name.as_deref().unwrap_or("wit_component");
+        let remote = format!("\"{REMOTE_PKG}/wit/");
+        let local = format!("\"{module}/{path}/");
+
+        let mut pending = Vec::new();
+        let import_sets = iter::once(&mut self.imports).chain(
+            self.interfaces
+                .values_mut()
+                .chain(self.export_interfaces.values_mut())
+                .map(|data| &mut data.imports),
+        );
+        for imports in import_sets {
+            *imports = mem::take(imports)
+                .into_iter()
+                .map(|import| {
+                    if let Some((_, name)) = import.split_once(&remote) {
+                        pending.push(name.trim_end_matches('"').to_string());
+                    }
+                    import.replace(&remote, &local)
+                })
+                .collect();
+        }
+
+        let mut copied = BTreeSet::new();
+        while let Some(name) = pending.pop() {
+            if !copied.insert(name.clone()) {
+                continue;
+            }
+            let src = dir.join("wit").join(&name);
+            let entries = fs::read_dir(&src)
+                .with_context(|| format!("unable to read `{}`", src.display()))?;
+            for entry in entries {
+                let entry = entry?;
+                let file_name = entry.file_name();
+                let Some(file_name) = file_name.to_str() else {
+                    continue;
+                };
+                if !(file_name.ends_with(".go") || file_name.ends_with(".s"))
+                    || file_name.ends_with("_test.go")
+                {
+                    continue;
+                }
+                let contents = fs::read_to_string(entry.path())
+                    .with_context(|| format!("unable to read `{}`", entry.path().display()))?;
+                pending.extend(
+                    contents
+                        .split(&remote)
+                        .skip(1)
+                        .filter_map(|rest| rest.split_once('"'))
+                        .map(|(name, _)| name.to_string()),
+                );
+                files.push(
+                    &format!("{path}/{name}/{file_name}"),
+                    contents.replace(&remote, &local).as_bytes(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     fn import(
         &mut self,
         resolve: &Resolve,
@@ -1092,62 +1378,72 @@ impl Go {
         );
         generator.imports = imports;
 
+        let mut context_code = String::new();
+        let mut context_results = String::new();
         let code = if async_ {
             generator.imports.insert(remote_pkg("async"));
 
-            let (lower, wasm_params) = if sig.indirect_params {
-                generator.imports.insert(remote_pkg("runtime"));
+            // Lowers the parameters, returning the code doing so and the call
+            // starting the subtask.
+            let lower_params = |generator: &mut FunctionGenerator<'_>| {
+                let (lower, wasm_params) = if sig.indirect_params {
+                    generator.imports.insert(remote_pkg("runtime"));
+
+                    let params_pointer = generator.locals.tmp("params");
+                    let abi = generator
+                        .generator
+                        .sizes
+                        .record(func.params.iter().map(|Param { ty, .. }| ty));
+                    let size = abi.size.format(POINTER_SIZE_EXPRESSION);
+                    let align = abi.align.format(POINTER_SIZE_EXPRESSION);
+                    let offsets = generator
+                        .generator
+                        .sizes
+                        .field_offsets(func.params.iter().map(|Param { ty, .. }| ty));
+
+                    for (name, (offset, ty)) in go_param_names.iter().zip(offsets) {
+                        let offset = offset.format(POINTER_SIZE_EXPRESSION);
+                        abi::lower_to_memory(
+                            resolve,
+                            generator,
+                            format!("unsafe.Add(unsafe.Pointer({params_pointer}), {offset})"),
+                            name.clone(),
+                            ty,
+                        );
+                    }
 
-                let params_pointer = generator.locals.tmp("params");
-                let abi = generator
-                    .generator
-                    .sizes
-                    .record(func.params.iter().map(|Param { ty, .. }| ty));
-                let size = abi.size.format(POINTER_SIZE_EXPRESSION);
-                let align = abi.align.format(POINTER_SIZE_EXPRESSION);
-                let offsets = generator
-                    .generator
-                    .sizes
-                    .field_offsets(func.params.iter().map(|Param { ty, .. }| ty));
-
-                for (name, (offset, ty)) in go_param_names.iter().zip(offsets) {
-                    let offset = offset.format(POINTER_SIZE_EXPRESSION);
-                    abi::lower_to_memory(
-                        resolve,
-                        &mut generator,
-                        format!("unsafe.Add(unsafe.Pointer({params_pointer}), {offset})"),
-                        name.clone(),
-                        ty,
-                    );
-                }
+                    let code = mem::take(&mut generator.src);
+                    generator.need_pinner = true;
+                    (
+                        format!(
+                            "{params_pointer} := witRuntime.Allocate({PINNER}, {size}, {align})\n{code}"
+                        ),
+                        vec![format!("uintptr({params_pointer})")],
+                    )
+                } else {
+                    let wasm_params = go_param_names
+                        .iter()
+                        .zip(&func.params)
+                        .flat_map(|(name, Param { ty, .. })| {
+                            abi::lower_flat(resolve, generator, name.clone(), ty)
+                        })
+                        .collect();
+                    (mem::take(&mut generator.src), wasm_params)
+                };
 
-                let code = mem::take(&mut generator.src);
-                generator.need_pinner = true;
-                (
-                    format!(
-                        "{params_pointer} := witRuntime.Allocate({PINNER}, {size}, {align})\n{code}"
-                    ),
-                    vec![format!("uintptr({params_pointer})")],
-                )
-            } else {
-                let wasm_params = go_param_names
+                let wasm_params = wasm_params
                     .iter()
-                    .zip(&func.params)
-                    .flat_map(|(name, Param { ty, .. })| {
-                        abi::lower_flat(resolve, &mut generator, name.clone(), ty)
-                    })
-                    .collect();
-                (mem::take(&mut generator.src), wasm_params)
+                    .map(|v| v.as_str())
+                    .chain(func.result.map(|_| IMPORT_RETURN_AREA))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                (lower, format!("uint32({raw_name}({wasm_params}))"))
             };
+            let (lower, call) = lower_params(&mut generator);
 
-            let wasm_params = wasm_params
-                .iter()
-                .map(|v| v.as_str())
-                .chain(func.result.map(|_| IMPORT_RETURN_AREA))
-                .collect::<Vec<_>>()
-                .join(", ");
-
-            let lift = if let Some(ty) = func.result {
+            // The code lifting the results, along with the values to return, if
+            // that code doesn't return them itself.
+            let (lift, results) = if let Some(ty) = func.result {
                 let result = abi::lift_from_memory(
                     resolve,
                     &mut generator,
@@ -1166,22 +1462,97 @@ impl Go {
                         .collect::<Vec<_>>()
                         .join(", ");
 
-                    format!(
-                        "{code}
-{tuple} := {result}
-return {results}"
+                    (
+                        format!(
+                            "{code}
+{tuple} := {result}"
+                        ),
+                        Some(results),
                     )
+                } else if let Some(result_ty) = generator.generator.error_result(resolve, func) {
+                    let ret = generator.return_error_result(resolve, result_ty, &result);
+                    (format!("{code}\n{ret}"), None)
                 } else {
-                    format!("{code}\nreturn {result}")
+                    (code, Some(result))
                 }
             } else {
-                String::new()
+                (String::new(), None)
             };
 
+            if generator.generator.opts.async_context {
+                let error_result = generator.generator.error_result(resolve, func).is_some();
+                let mut types = generator.generator.func_result_types(
+                    resolve,
+                    func,
+                    interface,
+                    true,
+                    &mut generator.imports,
+                );
+                if error_result {
+                    types.pop();
+                }
+                let mut context_zeros = String::new();
+                let zeros = types
+                    .iter()
+                    .map(|ty| {
+                        let zero = generator.locals.tmp("zero");
+                        context_zeros.push_str(&format!("var {zero} {ty}\n"));
+                        format!("{zero}, ")
+                    })
+                    .collect::<String>();
+                types.push("error".into());
+                context_results = if let [ty] = &types[..] {
+                    ty.clone()
+                } else {
+                    format!("({})", types.join(", "))
+                };
+                let ret = match &results {
+                    _ if error_result => String::new(),
+                    Some(results) => format!("return {results}, nil"),
+                    None => "return nil".into(),
+                };
+                // Lower the parameters once more, collecting how to hand the
+                // owned handles back in case the callee never takes them.
+                generator.collect_lifters = true;
+                generator.lifter_count = 0;
+                let (context_lower, context_call) = lower_params(&mut generator);
+                generator.collect_lifters = false;
+                let (lifters, restore) = if generator.lifter_count > 0 {
+                    (
+                        format!("lifters := make([]func(), 0, {})\n", generator.lifter_count),
+                        "if state == subtaskStartCancelled {
+                // The callee never took the arguments, so they're still ours.
+                for _, lifter := range lifters {
+                        lifter()
+                }
+        }",
+                    )
+                } else {
+                    (String::new(), "")
+                };
+                context_code = format!(
+                    "{lifters}{context_lower}
+if state := wasm_subtask_wait_context(ctx, {context_call}); state != subtaskReturned {{
+        {restore}
+        {context_zeros}
+        return {zeros}ctx.Err()
+}}
+{lift}
+{ret}
+"
+                );
+                generator.imports.insert(r#""context""#.into());
+            }
+
+            let ret = results
+                .map(|results| format!("return {results}"))
+                .unwrap_or_default();
+
             format!(
                 "{lower}
-witAsync.SubtaskWait(uint32({raw_name}({wasm_params})))
+witAsync.SubtaskWait({call})
 {lift}
+{ret}
 "
             )
         } else {
@@ -1229,7 +1600,26 @@ defer {PINNER}.Unpin()
             String::new()
         };
 
-        InterfaceData::from_generator_and_code(
+        let context_func = if context_code.is_empty() {
+            String::new()
+        } else {
+            let go_params = if go_params.is_empty() {
+                "ctx context.Context".to_string()
+            } else {
+                format!("ctx context.Context, {go_params}")
+            };
+            format!(
+                "
+func {camel}Context({go_params}) {context_results} {{
+        {pinner}
+        {return_area}
+        {context_code}
+}}
+"
+            )
+        };
+
+        let mut data = InterfaceData::from_generator_and_code(
             generator,
             format!(
                 "
@@ -1241,9 +1631,14 @@ func {camel}({go_params}) {go_results} {{
         {return_area}
         {code}
 }}
-"
+{context_func}"
             ),
-        )
+        );
+        if !context_func.is_empty() {
+            data.need_unsafe = true;
+            data.need_subtask_context = true;
+        }
+        data
     }
 
     fn export(
@@ -1305,9 +1700,11 @@ func {camel}({go_params}) {go_results} {{
         );
         let code = generator.src;
         let imports = generator.imports;
-        let need_unsafe = generator.need_unsafe;
+        let need_error_context = generator.need_error_context;
+        let need_unsafe = generator.need_unsafe || need_error_context;
         self.need_math |= generator.need_math;
         self.need_unsafe |= need_unsafe;
+        self.need_error_context |= need_error_context;
         self.imports.extend(imports);
 
         let (pinner, other, start, end) = if async_ {
@@ -1452,22 +1849,63 @@ func wasm_export_{name}({params}) {results} {{
         in_import: bool,
         imports: &mut BTreeSet<String>,
     ) -> String {
-        if let Some(ty) = &func.result {
-            if let Type::Id(id) = ty
-                && let TypeDefKind::Tuple(tuple) = &resolve.types[*id].kind
-            {
-                let types = tuple
-                    .types
-                    .iter()
-                    .map(|ty| self.type_name(resolve, *ty, interface, in_import, imports))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!("({types})")
-            } else {
-                self.type_name(resolve, *ty, interface, in_import, imports)
-            }
+        let types = self.func_result_types(resolve, func, interface, in_import, imports);
+        match &types[..] {
+            [] => String::new(),
+            [ty] => ty.clone(),
+            types => format!("({})", types.join(", ")),
+        }
+    }
+
+    /// Returns the Go types `func` returns, in order.
+    fn func_result_types(
+        &mut self,
+        resolve: &Resolve,
+        func: &Function,
+        interface: Option<&WorldKey>,
+        in_import: bool,
+        imports: &mut BTreeSet<String>,
+    ) -> Vec<String> {
+        if let Some(result) = self.error_result(resolve, func) {
+            return result
+                .ok
+                .map(|ty| self.type_name(resolve, ty, interface, in_import, imports))
+                .into_iter()
+                .chain(iter::once("error".into()))
+                .collect();
+        }
+        let Some(ty) = func.result else {
+            return Vec::new();
+        };
+        if let Type::Id(id) = ty
+            && let TypeDefKind::Tuple(tuple) = &resolve.types[id].kind
+        {
+            tuple
+                .types
+                .iter()
+                .map(|ty| self.type_name(resolve, *ty, interface, in_import, imports))
+                .collect()
         } else {
-            String::new()
+            vec![self.type_name(resolve, ty, interface, in_import, imports)]
+        }
+    }
+
+    /// Returns the `result` returned by `func` if it's bound as returning
+    /// `error` per `--results-as-errors`.
+    fn error_result<'r>(&self, resolve: &'r Resolve, func: &Function) -> Option<&'r Result_> {
+        if !self.opts.results_as_errors {
+            return None;
+        }
+        let mut ty = func.result?;
+        loop {
+            let Type::Id(id) = ty else {
+                return None;
+            };
+            match &resolve.types[id].kind {
+                TypeDefKind::Result(result) => return Some(result),
+                TypeDefKind::Type(inner) => ty = *inner,
+                _ => return None,
+            }
         }
     }
 
@@ -1632,6 +2070,8 @@ struct FunctionGenerator<'a> {
     return_area_size: ArchitectureSize,
     return_area_align: Alignment,
     imports: BTreeSet<String>,
+    need_result_error: bool,
+    need_error_context: bool,
 }
 
 impl<'a> FunctionGenerator<'a> {
@@ -1672,6 +2112,37 @@ impl<'a> FunctionGenerator<'a> {
             return_area_size: ArchitectureSize::default(),
             return_area_align: Alignment::default(),
             imports: BTreeSet::new(),
+            need_result_error: false,
+            need_error_context: false,
+        }
+    }
+
+    /// Returns `result`, a lifted `witTypes.Result`, as `(T, error)` or
+    /// `error`.
+    fn return_error_result(&mut self, resolve: &Resolve, ty: &Result_, result: &str) -> String {
+        self.need_result_error = true;
+        let err_type = ty
+            .err
+            .map(|ty| self.type_name(resolve, ty))
+            .unwrap_or_else(|| "witTypes.Unit".into());
+        let error = format!("&ResultError[{err_type}]{{Value: {result}.Err()}}");
+        if let Some(ok) = ty.ok {
+            let ok_type = self.type_name(resolve, ok);
+            let zero = self.locals.tmp("zero");
+            format!(
+                "if {result}.Tag() == witTypes.ResultErr {{
+        var {zero} {ok_type}
+        return {zero}, {error}
+}}
+return {result}.Ok(), nil"
+            )
+        } else {
+            format!(
+                "if {result}.Tag() == witTypes.ResultErr {{
+        return {error}
+}}
+return nil"
+            )
         }
     }
 
@@ -1685,6 +2156,75 @@ impl<'a> FunctionGenerator<'a> {
         )
     }
 
+    /// Calls an export bound as returning `(T, error)` or `error` and turns
+    /// what it returns into the `witTypes.Result` named `result`.
+    fn result_from_error(
+        &mut self,
+        resolve: &Resolve,
+        ty: &Result_,
+        result_type: Type,
+        call: &str,
+        result: &str,
+    ) {
+        self.imports.insert(remote_pkg("types"));
+        let package = self.generator.go_package_name(resolve, self.interface);
+        self.generator
+            .interfaces
+            .entry(package.clone())
+            .or_default()
+            .need_result_error = true;
+        let err = self.locals.tmp("err");
+        let result_type = self.type_name(resolve, result_type);
+        let ok_type = ty
+            .ok
+            .map(|ty| self.type_name(resolve, ty))
+            .unwrap_or_else(|| "witTypes.Unit".into());
+        let (bindings, ok_value) = if ty.ok.is_some() {
+            let ok = self.locals.tmp("ok");
+            (format!("{ok}, {err}"), ok)
+        } else {
+            (err.clone(), "witTypes.Unit{}".into())
+        };
+        let (unwrap, err_type, err_value) = match ty.err {
+            None => (
+                String::new(),
+                "witTypes.Unit".into(),
+                "witTypes.Unit{}".into(),
+            ),
+            Some(Type::String) => (String::new(), "string".into(), format!("{err}.Error()")),
+            Some(err_ty) => {
+                let err_type = self.type_name(resolve, err_ty);
+                self.imports.insert(self.generator.mod_pkg(false, &package));
+                self.imports.insert(r#""errors""#.into());
+                self.imports.insert(r#""fmt""#.into());
+                let wrapped = self.locals.tmp("wrapped");
+                // Without a `ResultError` there's no payload for the `err`
+                // case, so trap as documented for `--results-as-errors`.
+                (
+                    format!(
+                        "var {wrapped} *{package}.ResultError[{err_type}]
+        if !errors.As({err}, &{wrapped}) {{
+                panic(fmt.Errorf(\"error doesn't wrap a *{package}.ResultError[{err_type}]: %w\", {err}))
+        }}"
+                    ),
+                    err_type,
+                    format!("{wrapped}.Value"),
+                )
+            }
+        };
+        uwriteln!(
+            self.src,
+            "{bindings} := {call}
+var {result} {result_type}
+if {err} == nil {{
+        {result} = witTypes.Ok[{ok_type}, {err_type}]({ok_value})
+}} else {{
+        {unwrap}
+        {result} = witTypes.Err[{ok_type}, {err_type}]({err_value})
+}}"
+        );
+    }
+
     fn package_for_owner(
         &mut self,
         resolve: &Resolve,
@@ -1891,6 +2431,71 @@ for index := 0; index < int({length}); index++ {{
                 );
                 results.push(result);
             }
+            Instruction::FixedLengthListLower { size, .. } => {
+                let array = self.locals.tmp("array");
+                uwriteln!(self.src, "{array} := {}", operands[0]);
+                results.extend((0..*size).map(|index| format!("{array}[{index}]")));
+            }
+            Instruction::FixedLengthListLift { element, size, .. } => {
+                let element_type = self.type_name(resolve, **element);
+                let elements = operands.join(", ");
+                results.push(format!("[{size}]{element_type}{{{elements}}}"));
+            }
+            Instruction::FixedLengthListLowerToMemory { element, .. } => {
+                self.need_unsafe = true;
+                let (body, _) = self.blocks.pop().unwrap();
+                let value = &operands[0];
+                let address = &operands[1];
+                let size = self
+                    .generator
+                    .sizes
+                    .size(element)
+                    .format(POINTER_SIZE_EXPRESSION);
+                uwriteln!(
+                    self.src,
+                    "for index, {ITER_ELEMENT} := range {value} {{
+        {ITER_BASE_POINTER} := unsafe.Add(unsafe.Pointer({address}), index * {size})
+        {body}
+}}
+"
+                );
+            }
+            Instruction::FixedLengthListLiftFromMemory { element, size, .. } => {
+                self.need_unsafe = true;
+                let (body, body_results) = self.blocks.pop().unwrap();
+                let address = &operands[0];
+                let result = self.locals.tmp("result");
+                let element_size = self
+                    .generator
+                    .sizes
+                    .size(element)
+                    .format(POINTER_SIZE_EXPRESSION);
+                let element_type = self.type_name(resolve, **element);
+                let body_result = &body_results[0];
+                uwriteln!(
+                    self.src,
+                    "var {result} [{size}]{element_type}
+for index := range {result} {{
+        {ITER_BASE_POINTER} := unsafe.Add(unsafe.Pointer({address}), index * {element_size})
+        {body}
+        {result}[index] = {body_result}
+}}
+"
+                );
+                results.push(result);
+            }
+            Instruction::ErrorContextLower => {
+                self.need_pinner = true;
+                self.need_error_context = true;
+                results.push(format!(
+                    "wasm_error_context_lower({PINNER}, {})",
+                    operands[0]
+                ));
+            }
+            Instruction::ErrorContextLift => {
+                self.need_error_context = true;
+                results.push(format!("wasm_error_context_lift({})", operands[0]));
+            }
             Instruction::CallInterface { func, .. } => {
                 if self.unpin_params {
                     self.imports.insert(remote_pkg("runtime"));
@@ -1939,9 +2544,6 @@ for index := 0; index < int({length}); index++ {{
                         && let TypeDefKind::Tuple(tuple) = &resolve.types[ty].kind
                     {
                         let count = tuple.types.len();
-                        self.generator.tuples.insert(count);
-                        self.imports.insert(remote_pkg("types"));
-
                         let names = (0..count)
                             .map(|_| self.locals.tmp("result"))
                             .collect::<Vec<_>>();
@@ -1952,18 +2554,15 @@ for index := 0; index < int({length}); index++ {{
                             .map(|(idx, name)| format!("F{idx}: {name}"))
                             .collect::<Vec<_>>()
                             .join(", ");
-                        let types = tuple
-                            .types
-                            .iter()
-                            .map(|&ty| self.type_name(resolve, ty))
-                            .collect::<Vec<_>>()
-                            .join(", ");
+                        let tuple = self.type_name(resolve, Type::Id(ty));
 
                         uwriteln!(
                             self.src,
                             "{bindings} := {call}
-{result} := witTypes.Tuple{count}[{types}]{{{fields}}}"
+{result} := {tuple}{{{fields}}}"
                         );
+                    } else if let Some(result_ty) = self.generator.error_result(resolve, func) {
+                        self.result_from_error(resolve, result_ty, ty, &call, &result);
                     } else {
                         uwriteln!(self.src, "{result} := {call}");
                     }
@@ -1992,6 +2591,11 @@ for index := 0; index < int({length}); index++ {{
                             "{tuple} := {result}
 return {results}"
                         );
+                    } else if self.in_import
+                        && let Some(result_ty) = self.generator.error_result(resolve, func)
+                    {
+                        let ret = self.return_error_result(resolve, result_ty, result);
+                        uwriteln!(self.src, "{ret}");
                     } else {
                         uwriteln!(self.src, "return {result}");
                     }
@@ -2129,32 +2733,24 @@ if {value} {{
                     results.push(format!("({op}).F{index}"));
                 }
             }
-            Instruction::TupleLift { tuple, .. } => {
-                let count = tuple.types.len();
-                self.generator.tuples.insert(count);
-                let types = tuple
-                    .types
-                    .iter()
-                    .map(|&ty| self.type_name(resolve, ty))
-                    .collect::<Vec<_>>()
-                    .join(", ");
+            Instruction::TupleLift { ty, .. } => {
+                let tuple = self.type_name(resolve, Type::Id(*ty));
                 let fields = operands
                     .iter()
                     .enumerate()
                     .map(|(idx, val)| format!("F{idx}: {val}"))
                     .collect::<Vec<String>>()
                     .join(", ");
-                self.imports.insert(remote_pkg("types"));
-                results.push(format!("witTypes.Tuple{count}[{types}]{{{fields}}}"));
+                results.push(format!("{tuple}{{{fields}}}"));
             }
             Instruction::FlagsLower { .. } => {
                 let value = operands.pop().unwrap();
                 results.push(format!("int32({value})"))
             }
-            Instruction::FlagsLift { flags, .. } => {
+            Instruction::FlagsLift { ty, .. } => {
                 let value = operands.pop().unwrap();
-                let repr = flags_repr(flags);
-                results.push(format!("{repr}({value})"))
+                let ty = self.type_name(resolve, Type::Id(*ty));
+                results.push(format!("{ty}({value})"))
             }
             Instruction::RecordLower { record, .. } => {
                 let op = &operands[0];
@@ -2383,8 +2979,9 @@ default:
                 results.push(result);
             }
             Instruction::EnumLower { .. } => results.push(format!("int32({})", operands[0])),
-            Instruction::EnumLift { enum_, .. } => {
-                results.push(format!("{}({})", int_repr(enum_.tag()), operands[0]))
+            Instruction::EnumLift { ty, .. } => {
+                let ty = self.type_name(resolve, Type::Id(*ty));
+                results.push(format!("{ty}({})", operands[0]))
             }
             Instruction::VariantLower {
                 ty,
@@ -2727,6 +3324,206 @@ impl<'a> InterfaceGenerator<'a> {
             &mut self.imports,
         )
     }
+
+    /// Returns the `String`, `MarshalJSON` and `UnmarshalJSON` methods for an
+    /// enum named `name`, encoding it as the name of its case.
+    fn enum_methods(&mut self, name: &str, enum_: &Enum) -> String {
+        self.imports.insert(r#""encoding/json""#.into());
+        self.imports.insert(r#""fmt""#.into());
+
+        let repr = int_repr(enum_.tag());
+        let count = enum_.cases.len();
+        let (names, cases) = enum_
+            .cases
+            .iter()
+            .map(|case| {
+                let constant = format!("{name}{}", case.name.to_upper_camel_case());
+                let case = &case.name;
+                (
+                    format!("case {constant}:\nreturn \"{case}\"\n"),
+                    format!("case \"{case}\":\n*self = {constant}\n"),
+                )
+            })
+            .collect::<(String, String)>();
+
+        format!(
+            r#"
+func (self {name}) String() string {{
+        switch self {{
+        {names}
+        default:
+                return fmt.Sprintf("{name}(%d)", {repr}(self))
+        }}
+}}
+
+func (self {name}) MarshalJSON() ([]byte, error) {{
+        if int(self) >= {count} {{
+                return nil, fmt.Errorf("invalid {name}: %d", {repr}(self))
+        }}
+        return json.Marshal(self.String())
+}}
+
+func (self *{name}) UnmarshalJSON(data []byte) error {{
+        var name string
+        if err := json.Unmarshal(data, &name); err != nil {{
+                return err
+        }}
+        switch name {{
+        {cases}
+        default:
+                return fmt.Errorf("invalid {name}: %q", name)
+        }}
+        return nil
+}}
+"#
+        )
+    }
+
+    /// Returns the `String`, `MarshalJSON` and `UnmarshalJSON` methods for a
+    /// flags type named `name`, encoding it as an array of the set flags' names.
+    fn flags_methods(&mut self, name: &str, flags: &Flags) -> String {
+        self.imports.insert(r#""encoding/json""#.into());
+        self.imports.insert(r#""fmt""#.into());
+        self.imports.insert(r#""strings""#.into());
+
+        let (names, cases) = flags
+            .flags
+            .iter()
+            .map(|flag| {
+                let constant = format!("{name}{}", flag.name.to_upper_camel_case());
+                let flag = &flag.name;
+                (
+                    format!("if self&{constant} != 0 {{\nnames = append(names, \"{flag}\")\n}}\n"),
+                    format!("case \"{flag}\":\n*self |= {constant}\n"),
+                )
+            })
+            .collect::<(String, String)>();
+
+        format!(
+            r#"
+func (self {name}) names() []string {{
+        names := []string{{}}
+        {names}
+        return names
+}}
+
+func (self {name}) String() string {{
+        return strings.Join(self.names(), "|")
+}}
+
+func (self {name}) MarshalJSON() ([]byte, error) {{
+        return json.Marshal(self.names())
+}}
+
+func (self *{name}) UnmarshalJSON(data []byte) error {{
+        var names []string
+        if err := json.Unmarshal(data, &names); err != nil {{
+                return err
+        }}
+        *self = 0
+        for _, name := range names {{
+                switch name {{
+                {cases}
+                default:
+                        return fmt.Errorf("invalid {name} flag: %q", name)
+                }}
+        }}
+        return nil
+}}
+"#
+        )
+    }
+
+    /// Returns the `String`, `MarshalJSON` and `UnmarshalJSON` methods for a
+    /// variant named `name`, encoding it as an object with the case name as
+    /// `tag` and the payload, if any, as `value`.
+    fn variant_methods(&mut self, name: &str, variant: &Variant) -> String {
+        self.imports.insert(r#""encoding/json""#.into());
+        self.imports.insert(r#""fmt""#.into());
+
+        let mut names = String::new();
+        let mut tags = String::new();
+        let mut cases = String::new();
+        for case in &variant.cases {
+            let camel = case.name.to_upper_camel_case();
+            let constant = format!("{name}{camel}");
+            let case_name = &case.name;
+            uwriteln!(tags, "case {constant}:\ntag = \"{case_name}\"");
+            if let Some(ty) = case.ty {
+                let ty = self.type_name(self.resolve, ty);
+                uwriteln!(
+                    names,
+                    "case {constant}:\nreturn fmt.Sprintf(\"{case_name}(%v)\", self.value)"
+                );
+                uwriteln!(
+                    cases,
+                    "case \"{case_name}\":
+var value {ty}
+if err := json.Unmarshal(tagged.Value, &value); err != nil {{
+        return err
+}}
+*self = Make{constant}(value)"
+                );
+            } else {
+                uwriteln!(names, "case {constant}:\nreturn \"{case_name}\"");
+                uwriteln!(cases, "case \"{case_name}\":\n*self = Make{constant}()");
+            }
+        }
+
+        // A `string` case with a payload already has a `String` getter.
+        let string = if variant
+            .cases
+            .iter()
+            .any(|case| case.ty.is_some() && case.name.to_upper_camel_case() == "String")
+        {
+            String::new()
+        } else {
+            format!(
+                r#"
+func (self {name}) String() string {{
+        switch self.tag {{
+        {names}
+        default:
+                return fmt.Sprintf("{name}(%d)", self.tag)
+        }}
+}}
+"#
+            )
+        };
+
+        format!(
+            r#"{string}
+func (self {name}) MarshalJSON() ([]byte, error) {{
+        var tag string
+        switch self.tag {{
+        {tags}
+        default:
+                return nil, fmt.Errorf("invalid {name} tag: %d", self.tag)
+        }}
+        return json.Marshal(struct {{
+                Tag string `json:"tag"`
+                Value any `json:"value,omitempty"`
+        }}{{tag, self.value}})
+}}
+
+func (self *{name}) UnmarshalJSON(data []byte) error {{
+        var tagged struct {{
+                Tag string `json:"tag"`
+                Value json.RawMessage `json:"value"`
+        }}
+        if err := json.Unmarshal(data, &tagged); err != nil {{
+                return err
+        }}
+        switch tagged.Tag {{
+        {cases}
+        default:
+                return fmt.Errorf("invalid {name} tag: %q", tagged.Tag)
+        }}
+        return nil
+}}
+"#
+        )
+    }
 }
 
 impl<'a> wit_bindgen_core::InterfaceGenerator<'a> for InterfaceGenerator<'a> {
@@ -2743,8 +3540,13 @@ impl<'a> wit_bindgen_core::InterfaceGenerator<'a> for InterfaceGenerator<'a> {
             .map(|field| {
                 let ty = self.type_name(self.resolve, field.ty);
                 let docs = format_docs(&field.docs);
+                let tag = if self.generator.opts.generate_value_methods {
+                    format!(" `json:\"{}\"`", field.name)
+                } else {
+                    String::new()
+                };
                 let field = field.name.to_upper_camel_case();
-                format!("{docs}{field} {ty}")
+                format!("{docs}{field} {ty}{tag}")
             })
             .collect::<Vec<_>>()
             .join("\n");
@@ -2910,13 +3712,19 @@ func (self *{camel}) OnDrop() {{}}
             .map(|(i, flag)| {
                 let docs = format_docs(&flag.docs);
                 let flag = flag.name.to_upper_camel_case();
-                format!("{docs}{name}{flag} {repr} = 1 << {i}")
+                format!("{docs}{name}{flag} {name} = 1 << {i}")
             })
             .collect::<Vec<_>>()
             .join("\n");
 
         let docs = format_docs(docs);
 
+        let (decl, methods) = if self.generator.opts.generate_value_methods {
+            ("", self.flags_methods(&name, flags))
+        } else {
+            ("= ", String::new())
+        };
+
         uwriteln!(
             self.src,
             "
@@ -2924,27 +3732,22 @@ const (
 {constants}
 )
 
-{docs}type {name} = {repr}"
+{docs}type {name} {decl}{repr}
+{methods}"
         )
     }
 
     fn type_tuple(&mut self, _: TypeId, name: &str, tuple: &Tuple, docs: &Docs) {
-        self.imports.insert(remote_pkg("types"));
-        let count = tuple.types.len();
-        self.generator.tuples.insert(count);
         let name = name.to_upper_camel_case();
         let docs = format_docs(docs);
         let types = tuple
             .types
             .iter()
             .map(|ty| self.type_name(self.resolve, *ty))
-            .collect::<Vec<_>>()
-            .join(", ");
+            .collect::<Vec<_>>();
+        let tuple = self.generator.tuple_type(&types, &mut self.imports);
 
-        uwriteln!(
-            self.src,
-            "{docs}type {name} = witTypes.Tuple{count}[{types}]"
-        );
+        uwriteln!(self.src, "{docs}type {name} = {tuple}");
     }
 
     fn type_variant(&mut self, _: TypeId, name: &str, variant: &Variant, docs: &Docs) {
@@ -3006,6 +3809,12 @@ const (
             .collect::<Vec<_>>()
             .concat();
 
+        let methods = if self.generator.opts.generate_value_methods {
+            self.variant_methods(&name, variant)
+        } else {
+            String::new()
+        };
+
         let docs = format_docs(docs);
 
         uwriteln!(
@@ -3026,7 +3835,7 @@ func (self {name}) Tag() {repr} {{
 
 {getters}
 {constructors}
-"
+{methods}"
         )
     }
 
@@ -3076,20 +3885,27 @@ func (self {name}) Tag() {repr} {{
             .map(|(i, case)| {
                 let docs = format_docs(&case.docs);
                 let case = case.name.to_upper_camel_case();
-                format!("{docs}{name}{case} {repr} = {i}")
+                format!("{docs}{name}{case} {name} = {i}")
             })
             .collect::<Vec<_>>()
             .join("\n");
 
         let docs = format_docs(docs);
 
+        let (decl, methods) = if self.generator.opts.generate_value_methods {
+            ("", self.enum_methods(&name, enum_))
+        } else {
+            ("= ", String::new())
+        };
+
         uwriteln!(
             self.src,
             "
 const (
         {constants}
 )
-{docs}type {name} = {repr}"
+{docs}type {name} {decl}{repr}
+{methods}"
         )
     }
 
@@ -3123,8 +3939,7 @@ const (
     }
 
     fn type_builtin(&mut self, id: TypeId, name: &str, ty: &Type, docs: &Docs) {
-        _ = (id, name, ty, docs);
-        todo!()
+        self.type_alias(id, name, ty, docs);
     }
 
     fn type_future(&mut self, id: TypeId, name: &str, _: &Option<Type>, docs: &Docs) {
@@ -3142,6 +3957,62 @@ const (
     }
 }
 
+/// Binds `error-context` as Go's `error`, emitted into each package using it.
+///
+/// Values lifted from the canonical ABI keep their handle, dropping it once
+/// they're garbage collected, and lower back to it, including when they've been
+/// passed to another package.  Any other `error` is lowered to a new
+/// `error-context` with its `Error()` as the debug message.
+const ERROR_CONTEXT_SUPPORT: &str = r#"
+//go:wasmimport $root [error-context-new-utf8]
+func wasm_error_context_new(utf8 unsafe.Pointer, length uint32) int32
+
+//go:wasmimport $root [error-context-debug-message-utf8]
+func wasm_error_context_debug_message(handle int32, result unsafe.Pointer)
+
+//go:wasmimport $root [error-context-drop]
+func wasm_error_context_drop(handle int32)
+
+type wasmErrorContext struct {
+        handle int32
+}
+
+func (self *wasmErrorContext) Error() string {
+        var result [2]uint32
+        wasm_error_context_debug_message(self.handle, unsafe.Pointer(&result))
+        return unsafe.String((*uint8)(unsafe.Pointer(uintptr(result[0]))), result[1])
+}
+
+func (self *wasmErrorContext) ErrorContextHandle() int32 {
+        return self.handle
+}
+
+func wasm_error_context_lift(handle int32) error {
+        value := &wasmErrorContext{handle}
+        runtime.SetFinalizer(value, func(value *wasmErrorContext) {
+                wasm_error_context_drop(value.handle)
+        })
+        return value
+}
+
+func wasm_error_context_lower(pinner *runtime.Pinner, value error) int32 {
+        if value == nil {
+                panic("nil error")
+        }
+        if value, ok := value.(interface{ ErrorContextHandle() int32 }); ok {
+                // Keep the handle from being dropped until the call is done.
+                pinner.Pin(value)
+                return value.ErrorContextHandle()
+        }
+        message := value.Error()
+        utf8 := unsafe.Pointer(unsafe.StringData(message))
+        pinner.Pin(utf8)
+        handle := wasm_error_context_new(utf8, uint32(len(message)))
+        pinner.Pin(wasm_error_context_lift(handle))
+        return handle
+}
+"#;
+
 fn wasm_type(ty: WasmType) -> &'static str {
     match ty {
         WasmType::I32 => "int32",
@@ -3256,7 +4127,8 @@ fn any(resolve: &Resolve, ty: Type, fun: &dyn Fn(Type) -> bool) -> bool {
         | Type::F32
         | Type::F64
         | Type::Char
-        | Type::String => false,
+        | Type::String
+        | Type::ErrorContext => false,
         Type::Id(id) => {
             let ty = &resolve.types[id];
             match &ty.kind {
@@ -3272,14 +4144,14 @@ fn any(resolve: &Resolve, ty: Type, fun: &dyn Fn(Type) -> bool) -> bool {
                     .cases
                     .iter()
                     .any(|case| case.ty.map(|ty| any(resolve, ty, fun)).unwrap_or(false)),
-                TypeDefKind::Option(ty) | TypeDefKind::List(ty) | TypeDefKind::Type(ty) => {
-                    any(resolve, *ty, fun)
+                TypeDefKind::Option(ty)
+                | TypeDefKind::List(ty)
+                | TypeDefKind::FixedLengthList(ty, _)
+                | TypeDefKind::Type(ty) => any(resolve, *ty, fun),
+                TypeDefKind::Result(result) => {
+                    result.ok.is_some_and(|ty| any(resolve, ty, fun))
+                        || result.err.is_some_and(|ty| any(resolve, ty, fun))
                 }
-                TypeDefKind::Result(result) => result
-                    .ok
-                    .map(|ty| any(resolve, ty, fun))
-                    .or_else(|| result.err.map(|ty| any(resolve, ty, fun)))
-                    .unwrap_or(false),
                 TypeDefKind::Tuple(tuple) => tuple.types.iter().any(|ty| any(resolve, *ty, fun)),
                 TypeDefKind::Future(ty) | TypeDefKind::Stream(ty) => {
                     ty.map(|ty| any(resolve, ty, fun)).unwrap_or(false)
@@ -3287,10 +4159,9 @@ fn any(resolve: &Resolve, ty: Type, fun: &dyn Fn(Type) -> bool) -> bool {
                 TypeDefKind::Map(key, value) => {
                     any(resolve, *key, fun) || any(resolve, *value, fun)
                 }
-                _ => todo!("{:?}", ty.kind),
+                TypeDefKind::Unknown => unreachable!(),
             }
         }
-        _ => todo!("{ty:?}"),
     }
 }
 
@@ -291,9 +291,11 @@ const ASYNC_MUTEX: &str = include_str!("./async/mutex.mbt");
 const ASYNC_PRIMITIVE: &str = include_str!("./async/async_primitive.mbt");
 const ASYNC_PROMISE: &str = include_str!("./async/promise.mbt");
 const ASYNC_SCHEDULER: &str = include_str!("./async/scheduler.mbt");
+const ASYNC_SELECT: &str = include_str!("./async/select.mbt");
 const ASYNC_SEMAPHORE: &str = include_str!("./async/semaphore.mbt");
 const ASYNC_TASK: &str = include_str!("./async/task.mbt");
 const ASYNC_TASK_GROUP: &str = include_str!("./async/task_group.mbt");
+const ASYNC_TIMER: &str = include_str!("./async/timer.mbt");
 const ASYNC_TRAIT: &str = include_str!("./async/trait.mbt");
 const ASYNC_PKG: &str = include_str!("./async/moon.pkg.json");
 
@@ -302,7 +304,7 @@ struct Segment<'a> {
     src: &'a str,
 }
 
-const ASYNC_UTILS: [&Segment; 12] = [
+const ASYNC_UTILS: [&Segment; 14] = [
     &Segment {
         name: "async_primitive",
         src: ASYNC_PRIMITIVE,
@@ -347,6 +349,14 @@ const ASYNC_UTILS: [&Segment; 12] = [
         name: "async_task_group",
         src: ASYNC_TASK_GROUP,
     },
+    &Segment {
+        name: "async_timer",
+        src: ASYNC_TIMER,
+    },
+    &Segment {
+        name: "async_select",
+        src: ASYNC_SELECT,
+    },
     &Segment {
         name: "async_trait",
         src: ASYNC_TRAIT,
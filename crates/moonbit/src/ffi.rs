@@ -271,3 +271,12 @@ pub fn mbt_ffi_cabi_realloc(
 extern "wasm" fn mbt_ffi_copy(dest : Int, src : Int, len : Int) =
   #|(func (param i32) (param i32) (param i32) local.get 0 local.get 1 local.get 2 memory.copy)
 "#;
+
+/// The `ErrorContext` type, emitted as a package of its own so that worlds
+/// using `error-context` don't need the async runtime.
+pub(crate) const ERROR_CONTEXT: &str = include_str!("./ffi/error_context.mbt");
+
+pub(crate) const ERROR_CONTEXT_PKG: &str = r#"{
+  "supported-targets": "+wasm"
+}
+"#;
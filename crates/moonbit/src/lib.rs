@@ -1,5 +1,4 @@
 use anyhow::Result;
-use core::panic;
 use heck::{ToShoutySnakeCase, ToUpperCamelCase};
 use std::{
     collections::{HashMap, HashSet},
@@ -11,18 +10,19 @@ use wit_bindgen_core::{
     AsyncFilterSet, Direction, Files, InterfaceGenerator as CoreInterfaceGenerator, Ns, Source,
     WorldGenerator,
     abi::{self, AbiVariant, Bindgen, Bitcast, Instruction, LiftLower, WasmType},
-    uwrite, uwriteln,
+    dealias, uwrite, uwriteln,
     wit_parser::{
-        Alignment, ArchitectureSize, Docs, Enum, Flags, FlagsRepr, Function, Int, InterfaceId,
-        LiftLowerAbi, LiveTypes, ManglingAndAbi, Param, Record, Resolve, ResourceIntrinsic,
-        Result_, SizeAlign, Tuple, Type, TypeDefKind, TypeId, Variant, WasmExport, WasmExportKind,
-        WasmImport, WorldId, WorldKey,
+        Alignment, ArchitectureSize, Docs, Enum, Flags, FlagsRepr, Function, FunctionKind,
+        InterfaceId, LiftLowerAbi, LiveTypes, ManglingAndAbi, Param, Record, Resolve,
+        ResourceIntrinsic, Result_, SizeAlign, Tuple, Type, TypeDefKind, TypeId, Variant,
+        WasmExport, WasmExportKind, WasmImport, WorldId, WorldItem, WorldKey,
     },
 };
 
 use crate::async_support::{AsyncFunctionState, AsyncSupport};
 use crate::pkg::{
-    ASYNC_CORE_DIR, Imports, MoonbitSignature, PkgResolver, ToMoonBitIdent, ToMoonBitTypeIdent,
+    ASYNC_CORE_DIR, FFI_DIR, Imports, MoonbitSignature, PkgResolver, ToMoonBitIdent,
+    ToMoonBitTypeIdent,
 };
 
 mod async_support;
@@ -34,13 +34,13 @@ mod pkg;
 // - Encoding: UTF16
 // - Lift/Lower list<T>: T == Int/UInt/Int64/UInt64/Float/Double -> FixedArray[T], T == Byte -> Bytes, T == Char -> String
 // Organization:
-// - one package per interface (export and import are treated as different interfaces)
+// - one package per interface (export and import are treated as different interfaces, but an
+//   exported interface that is also imported aliases the import's resource-free types)
 // - ffi utils are under `./ffi`, and the project entrance (package as link target) is under `./gen`
 
 // We use Legacy mangling for MoonBit (no specific reason, just because we haven't switched yet)
 // We use AsyncCallback ABI for async functions
 
-// TODO: Export will share the type signatures with the import by using a newtype alias
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[derive(Default, Debug, Clone)]
@@ -131,6 +131,14 @@ pub struct MoonBit {
     export_ns: Ns,
 
     async_support: AsyncSupport,
+
+    // Package of an imported `wasi:clocks/monotonic-clock` able to drive the
+    // async runtime's timers, and whether it has an async `wait-until`.
+    monotonic_clock: Option<(String, bool)>,
+
+    // Whether the world uses `error-context`, whose type lives in the `ffi`
+    // package.
+    error_context: bool,
 }
 
 impl MoonBit {
@@ -155,6 +163,48 @@ impl MoonBit {
         }
     }
 
+    /// Recognizes an imported `wasi:clocks/monotonic-clock` whose `now` can
+    /// back the async runtime's timers, returning whether its `wait-until` is
+    /// also available as an async import to sleep on.
+    fn monotonic_clock_wait_until(
+        &mut self,
+        resolve: &Resolve,
+        key: &WorldKey,
+        id: InterfaceId,
+    ) -> Option<bool> {
+        let interface = &resolve.interfaces[id];
+        let package = &resolve.packages[interface.package?].name;
+        if package.namespace != "wasi"
+            || package.name != "clocks"
+            || interface.name.as_deref() != Some("monotonic-clock")
+        {
+            return None;
+        }
+        let is_u64 = |ty: &Type| match ty {
+            Type::Id(id) => matches!(
+                resolve.types[dealias(resolve, *id)].kind,
+                TypeDefKind::Type(Type::U64)
+            ),
+            ty => *ty == Type::U64,
+        };
+
+        let now = interface.functions.get("now")?;
+        if now.kind != FunctionKind::Freestanding
+            || !now.params.is_empty()
+            || !now.result.as_ref().is_some_and(is_u64)
+        {
+            return None;
+        }
+        let wait_until = interface.functions.get("wait-until").is_some_and(|func| {
+            matches!(func.kind, FunctionKind::AsyncFreestanding)
+                && func.params.len() == 1
+                && is_u64(&func.params[0].ty)
+                && func.result.is_none()
+                && self.opts.async_.is_async(resolve, Some(key), func, true)
+        });
+        Some(wait_until)
+    }
+
     fn write_moon_pkg(&self, moon_pkg: &mut Source, imports: Option<&Imports>, link: bool) {
         // Disable warning for invalid inline wasm
         moon_pkg.push_str("{\n\"warn-list\": \"-44\"");
@@ -179,9 +229,10 @@ impl MoonBit {
             moon_pkg.deindent(1);
             moon_pkg.push_str("\n]");
         }
-        let imports_async_core =
-            imports.is_some_and(|imports| imports.packages.contains_key(ASYNC_CORE_DIR));
-        if imports_async_core || (link && self.async_support.is_required()) {
+        let imports_wasm_only = imports.is_some_and(|imports| {
+            imports.packages.contains_key(ASYNC_CORE_DIR) || imports.packages.contains_key(FFI_DIR)
+        });
+        if imports_wasm_only || (link && self.async_support.is_required()) {
             moon_pkg.push_str(",\n\"supported-targets\": \"+wasm\"");
         }
         // Link target
@@ -251,6 +302,7 @@ impl WorldGenerator for MoonBit {
         if world_contains_future_or_stream(resolve, world) {
             self.async_support.require_runtime();
         }
+        self.error_context = world_contains_error_context(resolve, world);
 
         self.pkg_resolver.resolve = resolve.clone();
         self.project_name = self
@@ -279,6 +331,10 @@ impl WorldGenerator for MoonBit {
             .import_interface_names
             .insert(id, name.clone());
 
+        if let Some(wait_until) = self.monotonic_clock_wait_until(resolve, key, id) {
+            self.monotonic_clock = Some((name.clone(), wait_until));
+        }
+
         let mut r#gen = self.interface(resolve, &name, Direction::Import, Some(key));
         r#gen.types(id);
 
@@ -418,7 +474,7 @@ impl WorldGenerator for MoonBit {
             .insert(id, name.clone());
 
         let mut r#gen = self.interface(resolve, &name, Direction::Export, Some(key));
-        r#gen.types(id);
+        r#gen.export_types(id);
 
         for (_, func) in resolve.interfaces[id].functions.iter() {
             r#gen.export(func);
@@ -527,6 +583,17 @@ impl WorldGenerator for MoonBit {
         // If async is used, export async utils
         self.async_support.emit_runtime_files(files, VERSION);
 
+        if self.error_context {
+            files.push(
+                &format!("{FFI_DIR}/error_context.mbt"),
+                ffi::ERROR_CONTEXT.as_bytes(),
+            );
+            files.push(
+                &format!("{FFI_DIR}/moon.pkg.json"),
+                ffi::ERROR_CONTEXT_PKG.as_bytes(),
+            );
+        }
+
         // Export project files
         if !self.opts.ignore_stub && !self.opts.ignore_module_file {
             let mut body = Source::default();
@@ -549,6 +616,28 @@ impl WorldGenerator for MoonBit {
         for (_, (_, impl_)) in self.export.iter() {
             uwriteln!(&mut body, "{impl_}");
         }
+        // Drive the async runtime's timers with the imported monotonic clock;
+        // without one, `sleep` and `with_timeout` raise `NoClockError` unless
+        // the application calls `set_clock` itself.
+        if self.async_support.is_required()
+            && let Some((clock, wait_until)) = self.monotonic_clock.clone()
+        {
+            let async_core = self
+                .pkg_resolver
+                .qualify_package(&self.opts.r#gen_dir, ASYNC_CORE_DIR);
+            let clock = self
+                .pkg_resolver
+                .qualify_package(&self.opts.r#gen_dir, &clock);
+            let wait_until = if wait_until {
+                format!(", wait_until={clock}wait_until")
+            } else {
+                String::new()
+            };
+            uwriteln!(
+                &mut body,
+                "///|\nfn init {{\n{async_core}set_clock({clock}now{wait_until})\n}}"
+            );
+        }
 
         files.push(
             &format!("{}/ffi.mbt", self.opts.r#gen_dir),
@@ -596,6 +685,43 @@ impl InterfaceGenerator<'_> {
         }
     }
 
+    /// Defines the types of an exported interface, aliasing the definitions
+    /// of the imported side of the same interface where possible so values
+    /// can be passed between the two without conversion.
+    fn export_types(&mut self, id: InterfaceId) {
+        for (name, ty) in self.resolve.interfaces[id].types.iter() {
+            let def = &self.resolve.types[*ty];
+            let package = match def.kind {
+                TypeDefKind::Type(_) => None,
+                _ => self.world_gen.pkg_resolver.shared_import_package(def),
+            };
+            let Some(package) = package.cloned() else {
+                self.define_type(name, *ty);
+                continue;
+            };
+            let qualifier = self
+                .world_gen
+                .pkg_resolver
+                .qualify_package(self.name, &package);
+            let name = name.to_moonbit_type_ident();
+            print_docs(&mut self.src, &def.docs);
+            uwrite!(
+                self.src,
+                "
+                pub typealias {qualifier}{name} as {name}
+                "
+            );
+            if let TypeDefKind::Flags(_) = def.kind {
+                uwrite!(
+                    self.src,
+                    "
+                    pub typealias {qualifier}{name}Flag as {name}Flag
+                    "
+                );
+            }
+        }
+    }
+
     fn import(&mut self, func: &Function) {
         let async_plan = self.world_gen.async_support.import_plan(
             &mut self.world_gen.opts.async_,
@@ -1115,7 +1241,7 @@ impl<'a> wit_bindgen_core::InterfaceGenerator<'a> for InterfaceGenerator<'a> {
             FlagsRepr::U8 => "Byte",
             FlagsRepr::U16 | FlagsRepr::U32(1) => "UInt",
             FlagsRepr::U32(2) => "UInt64",
-            _ => unreachable!(), // https://github.com/WebAssembly/component-model/issues/370
+            FlagsRepr::U32(_) => "FixedArray[UInt]",
         };
 
         let cases = flags
@@ -1125,6 +1251,73 @@ impl<'a> wit_bindgen_core::InterfaceGenerator<'a> for InterfaceGenerator<'a> {
             .collect::<Vec<_>>()
             .join("; ");
 
+        let mut deriviation: Vec<_> = Vec::new();
+        if self.derive_opts.derive_debug {
+            deriviation.push("Debug")
+        }
+        if self.derive_opts.derive_show {
+            deriviation.push("Show")
+        }
+        if self.derive_opts.derive_eq {
+            deriviation.push("Eq")
+        }
+        let declaration = if self.derive_opts.derive_error && name.contains("Error") {
+            "suberror"
+        } else {
+            "struct"
+        };
+
+        if let FlagsRepr::U32(words @ 3..) = flags.repr() {
+            // Too wide for a single integer, so store one `UInt` per flat
+            // `i32` and address flags by their bit index.
+            let map_to_index = flags
+                .flags
+                .iter()
+                .enumerate()
+                .map(|(i, flag)| format!("{} => {i}", flag.name.to_shouty_snake_case()))
+                .collect::<Vec<_>>()
+                .join("\n    ");
+
+            uwrite!(
+                self.src,
+                "
+                pub(all) {declaration} {name}({ty}) derive({})
+                pub fn {name}::default() -> {name} {{
+                    {name}(FixedArray::make({words}, 0U))
+                }}
+                pub(all) enum {name}Flag {{
+                    {cases}
+                }}
+                fn {name}Flag::index(self : {name}Flag) -> Int {{
+                  match self {{
+                    {map_to_index}
+                  }}
+                }}
+                pub fn {name}::set(self : Self, other: {name}Flag) -> {name} {{
+                  let {name}(flag) = self
+                  let flag = flag.copy()
+                  let index = other.index()
+                  flag[index / 32] = flag[index / 32].lor(1U << (index % 32))
+                  {name}(flag)
+                }}
+                pub fn {name}::unset(self : Self, other: {name}Flag) -> {name} {{
+                  let {name}(flag) = self
+                  let flag = flag.copy()
+                  let index = other.index()
+                  flag[index / 32] = flag[index / 32].land((1U << (index % 32)).lnot())
+                  {name}(flag)
+                }}
+                pub fn {name}::is_set(self : Self, other: {name}Flag) -> Bool {{
+                  let {name}(flag) = self
+                  let index = other.index()
+                  flag[index / 32].land(1U << (index % 32)) != 0U
+                }}
+                ",
+                deriviation.join(", "),
+            );
+            return;
+        }
+
         let map_to_int = flags
             .flags
             .iter()
@@ -1146,22 +1339,6 @@ impl<'a> wit_bindgen_core::InterfaceGenerator<'a> for InterfaceGenerator<'a> {
             .collect::<Vec<_>>()
             .join("\n    ");
 
-        let mut deriviation: Vec<_> = Vec::new();
-        if self.derive_opts.derive_debug {
-            deriviation.push("Debug")
-        }
-        if self.derive_opts.derive_show {
-            deriviation.push("Show")
-        }
-        if self.derive_opts.derive_eq {
-            deriviation.push("Eq")
-        }
-        let declaration = if self.derive_opts.derive_error && name.contains("Error") {
-            "suberror"
-        } else {
-            "struct"
-        };
-
         uwrite!(
             self.src,
             "
@@ -1721,70 +1898,59 @@ impl Bindgen for FunctionBindgen<'_, '_> {
             }
             Instruction::BoolFromI32 => results.push(format!("({} != 0)", operands[0])),
 
-            Instruction::FlagsLower { flags, ty, .. } => match flags_repr(flags) {
-                Int::U8 => {
-                    let op = &operands[0];
-                    let flag = self.locals.tmp("flag");
-                    let ty = self.resolve_constructor(&Type::Id(*ty));
-                    uwriteln!(
-                        self.src,
-                        r#"
-                        let {ty}({flag}) = {op}
-                        "#
-                    );
-                    results.push(format!("{flag}.to_int()"));
-                }
-                Int::U16 | Int::U32 => {
-                    let op = &operands[0];
-                    let flag = self.locals.tmp("flag");
-                    let ty = self.resolve_constructor(&Type::Id(*ty));
-                    uwriteln!(
-                        self.src,
-                        r#"
-                        let {ty}({flag}) = {op}
-                        "#
-                    );
-                    results.push(format!("{flag}.reinterpret_as_int()"));
-                }
-                Int::U64 => {
-                    let op = &operands[0];
-                    let flag = self.locals.tmp("flag");
-                    let ty = self.resolve_constructor(&Type::Id(*ty));
-                    uwriteln!(
-                        self.src,
-                        r#"
-                        let {ty}({flag}) = {op}
-                        "#
-                    );
-                    results.push(format!("({flag}.to_int())"));
-                    results.push(format!("({flag} >> 32).to_int())"));
+            Instruction::FlagsLower { flags, ty, .. } => {
+                let op = &operands[0];
+                let flag = self.locals.tmp("flag");
+                let ty = self.resolve_constructor(&Type::Id(*ty));
+                uwriteln!(
+                    self.src,
+                    r#"
+                    let {ty}({flag}) = {op}
+                    "#
+                );
+                match flags.repr() {
+                    FlagsRepr::U8 => results.push(format!("{flag}.to_int()")),
+                    FlagsRepr::U16 | FlagsRepr::U32(1) => {
+                        results.push(format!("{flag}.reinterpret_as_int()"))
+                    }
+                    FlagsRepr::U32(2) => {
+                        results.push(format!("({flag}.to_int())"));
+                        results.push(format!("(({flag} >> 32).to_int())"));
+                    }
+                    FlagsRepr::U32(words) => {
+                        for i in 0..words {
+                            results.push(format!("{flag}[{i}].reinterpret_as_int()"));
+                        }
+                    }
                 }
-            },
+            }
 
-            Instruction::FlagsLift { flags, ty, .. } => match flags_repr(flags) {
-                Int::U8 => {
-                    results.push(format!(
-                        "{}({}.to_byte())",
-                        self.resolve_type_name(&Type::Id(*ty)),
-                        operands[0]
-                    ));
-                }
-                Int::U16 | Int::U32 => {
-                    results.push(format!(
-                        "{}({}.reinterpret_as_uint())",
-                        self.resolve_type_name(&Type::Id(*ty)),
-                        operands[0]
-                    ));
-                }
-                Int::U64 => {
-                    results.push(format!(
-                        "{}(({}).reinterpret_as_uint().to_uint64() | (({}).reinterpret_as_uint().to_uint64() << 32))",
-                        self.resolve_type_name(&Type::Id(*ty)),
-                        operands[0],
-                        operands[1]
-                    ));
+            Instruction::FlagsLift { flags, ty, .. } => {
+                let ty = self.resolve_type_name(&Type::Id(*ty));
+                match flags.repr() {
+                    FlagsRepr::U8 => {
+                        results.push(format!("{ty}({}.to_byte())", operands[0]));
+                    }
+                    FlagsRepr::U16 | FlagsRepr::U32(1) => {
+                        results.push(format!("{ty}({}.reinterpret_as_uint())", operands[0]));
+                    }
+                    FlagsRepr::U32(2) => {
+                        results.push(format!(
+                            "{ty}(({}).reinterpret_as_uint().to_uint64() | (({}).reinterpret_as_uint().to_uint64() << 32))",
+                            operands[0],
+                            operands[1]
+                        ));
+                    }
+                    FlagsRepr::U32(_) => {
+                        let words = operands
+                            .iter()
+                            .map(|op| format!("({op}).reinterpret_as_uint()"))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        results.push(format!("{ty}([{words}])"));
+                    }
                 }
-            },
+            }
 
             Instruction::HandleLower { ty, .. } => {
                 let op = &operands[0];
@@ -2634,7 +2800,13 @@ impl Bindgen for FunctionBindgen<'_, '_> {
                     uwriteln!(self.src, "{}.{method}()", operands[0]);
                 }
             }
-            Instruction::ErrorContextLower { .. } | Instruction::ErrorContextLift { .. } => todo!(),
+            Instruction::ErrorContextLower { .. } => {
+                results.push(format!("({}).handle()", operands[0]));
+            }
+            Instruction::ErrorContextLift { .. } => {
+                let ty = self.resolve_type_name(&Type::ErrorContext);
+                results.push(format!("{ty}::from_handle({})", operands[0]));
+            }
             Instruction::FixedLengthListLift {
                 element: _,
                 size,
@@ -2667,6 +2839,7 @@ impl Bindgen for FunctionBindgen<'_, '_> {
                 element,
                 size: fixed_length,
                 id: _,
+                offset: _,
             } => {
                 let Block {
                     body,
@@ -2695,6 +2868,7 @@ impl Bindgen for FunctionBindgen<'_, '_> {
                 element,
                 size: fll_size,
                 id: _,
+                offset: _,
             } => {
                 let Block {
                     body,
@@ -2949,16 +3123,6 @@ fn wasm_type(ty: WasmType) -> &'static str {
     }
 }
 
-fn flags_repr(flags: &Flags) -> Int {
-    match flags.repr() {
-        FlagsRepr::U8 => Int::U8,
-        FlagsRepr::U16 => Int::U16,
-        FlagsRepr::U32(1) => Int::U32,
-        FlagsRepr::U32(2) => Int::U64,
-        repr => panic!("unimplemented flags {repr:?}"),
-    }
-}
-
 fn type_contains_future_or_stream(resolve: &Resolve, ty: &Type) -> bool {
     let mut live = LiveTypes::default();
     live.add_type(resolve, ty);
@@ -3078,6 +3242,47 @@ fn world_contains_future_or_stream(resolve: &Resolve, world: WorldId) -> bool {
     })
 }
 
+fn world_contains_error_context(resolve: &Resolve, world: WorldId) -> bool {
+    let is_error_context = |ty: &Type| matches!(ty, Type::ErrorContext);
+    let mut live = LiveTypes::default();
+    live.add_world(resolve, world);
+    let in_types = live.iter().any(|id| match &resolve.types[id].kind {
+        TypeDefKind::Type(ty)
+        | TypeDefKind::List(ty)
+        | TypeDefKind::FixedLengthList(ty, _)
+        | TypeDefKind::Option(ty)
+        | TypeDefKind::Future(Some(ty))
+        | TypeDefKind::Stream(Some(ty)) => is_error_context(ty),
+        TypeDefKind::Map(key, value) => is_error_context(key) || is_error_context(value),
+        TypeDefKind::Tuple(tuple) => tuple.types.iter().any(is_error_context),
+        TypeDefKind::Record(record) => record.fields.iter().any(|f| is_error_context(&f.ty)),
+        TypeDefKind::Variant(variant) => variant
+            .cases
+            .iter()
+            .any(|c| c.ty.as_ref().is_some_and(is_error_context)),
+        TypeDefKind::Result(result) => {
+            result.ok.as_ref().is_some_and(is_error_context)
+                || result.err.as_ref().is_some_and(is_error_context)
+        }
+        _ => false,
+    });
+    let world = &resolve.worlds[world];
+    let in_funcs = world
+        .imports
+        .values()
+        .chain(world.exports.values())
+        .flat_map(|item| match item {
+            WorldItem::Function(func) => vec![func],
+            WorldItem::Interface { id, .. } => resolve.interfaces[*id].functions.values().collect(),
+            WorldItem::Type { .. } => Vec::new(),
+        })
+        .any(|func| {
+            func.params.iter().any(|p| is_error_context(&p.ty))
+                || func.result.as_ref().is_some_and(is_error_context)
+        });
+    in_types || in_funcs
+}
+
 fn indent(code: &str) -> Source {
     let mut indented = Source::default();
     let mut was_empty = false;
@@ -3964,4 +4169,155 @@ mod tests {
         assert!(ffi.contains(r#""$root" "[async-lower][future-read-unit]exchange""#));
         assert!(ffi.contains(r#""$root" "[future-cancel-read-unit]exchange""#));
     }
+
+    #[test]
+    fn sync_error_context_uses_ffi_package() {
+        let files = generate(
+            r#"
+            package a:b;
+
+            interface errors {
+                describe: func(err: error-context) -> result<_, error-context>;
+            }
+
+            world service { export errors; }
+            "#,
+            "service",
+        );
+
+        let error_context = file(&files, "ffi/error_context.mbt");
+        assert!(
+            error_context.contains(r#""$root" "[error-context-new-utf16]""#),
+            "{error_context}"
+        );
+        assert!(file(&files, "ffi/moon.pkg.json").contains(r#""supported-targets": "+wasm""#));
+        // A synchronous world doesn't pull in the async runtime.
+        assert!(
+            files
+                .iter()
+                .all(|(name, _)| !name.starts_with("async-core/")),
+            "unexpected async runtime"
+        );
+        let ffi = file(&files, "gen/interface/a/b/errors/ffi.mbt");
+        assert!(ffi.contains("@ffi.ErrorContext::from_handle(p0)"), "{ffi}");
+        assert!(ffi.contains(".handle())"), "{ffi}");
+        let pkg = file(&files, "gen/interface/a/b/errors/moon.pkg.json");
+        assert!(pkg.contains("/ffi\""), "{pkg}");
+    }
+
+    #[test]
+    fn exports_alias_types_of_the_same_imported_interface() {
+        let files = generate(
+            r#"
+            package a:b;
+
+            interface shapes {
+                record point { x: s32, y: s32 }
+                flags perms { read, write }
+                resource thing;
+                record holder { t: thing }
+                shift: func(p: point, f: perms) -> point;
+            }
+
+            world proxy {
+                import shapes;
+                export shapes;
+            }
+            "#,
+            "proxy",
+        );
+
+        let top = file(&files, "gen/interface/a/b/shapes/top.mbt");
+        assert!(
+            top.contains("pub typealias @shapes.Point as Point"),
+            "{top}"
+        );
+        assert!(
+            top.contains("pub typealias @shapes.Perms as Perms"),
+            "{top}"
+        );
+        assert!(
+            top.contains("pub typealias @shapes.PermsFlag as PermsFlag"),
+            "{top}"
+        );
+        assert!(top.contains("pub(all) struct Thing(Int)"), "{top}");
+        assert!(top.contains("pub(all) struct Holder {"), "{top}");
+        assert!(
+            top.contains(
+                "declare pub fn shift(p : @shapes.Point, f : @shapes.Perms) -> @shapes.Point"
+            ),
+            "{top}"
+        );
+    }
+
+    #[test]
+    fn imported_monotonic_clock_drives_runtime_timers() {
+        let wit = |wait_until: &str| {
+            format!(
+                r#"
+                package a:b;
+
+                package wasi:clocks@0.3.0 {{
+                    interface monotonic-clock {{
+                        type mark = u64;
+                        now: func() -> mark;
+                        {wait_until}
+                    }}
+                }}
+
+                world w {{
+                    import wasi:clocks/monotonic-clock@0.3.0;
+                    export run: async func();
+                }}
+                "#
+            )
+        };
+
+        let files = generate(&wit("wait-until: async func(when: mark);"), "w");
+        assert!(
+            file(&files, "async-core/async_timer.mbt").contains("pub async fn[X] with_timeout(")
+        );
+        assert!(file(&files, "async-core/async_select.mbt").contains("pub async fn[X] race("));
+        let ffi = file(&files, "gen/ffi.mbt");
+        assert!(
+            ffi.contains(
+                "@async-core.set_clock(@monotonic-clock.now, wait_until=@monotonic-clock.wait_until)"
+            ),
+            "{ffi}"
+        );
+        let moon_pkg = file(&files, "gen/moon.pkg.json");
+        assert!(
+            moon_pkg.contains(r#""alias" : "monotonic-clock""#),
+            "{moon_pkg}"
+        );
+
+        let files = generate(&wit(""), "w");
+        let ffi = file(&files, "gen/ffi.mbt");
+        assert!(
+            ffi.contains("@async-core.set_clock(@monotonic-clock.now)"),
+            "{ffi}"
+        );
+    }
+
+    #[test]
+    fn sync_worlds_do_not_install_a_clock() {
+        let files = generate(
+            r#"
+            package a:b;
+
+            package wasi:clocks@0.2.0 {
+                interface monotonic-clock {
+                    now: func() -> u64;
+                }
+            }
+
+            world w {
+                import wasi:clocks/monotonic-clock@0.2.0;
+                export run: func();
+            }
+            "#,
+            "w",
+        );
+        assert!(!file(&files, "gen/ffi.mbt").contains("set_clock"));
+    }
 }
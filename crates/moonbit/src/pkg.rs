@@ -4,12 +4,13 @@ use heck::{ToSnakeCase, ToUpperCamelCase};
 use wit_bindgen_core::{
     Ns, dealias,
     wit_parser::{
-        Function, FunctionKind, Handle, InterfaceId, Param, Resolve, Type, TypeDef, TypeDefKind,
-        TypeOwner, WorldId, WorldKey,
+        Function, FunctionKind, Handle, InterfaceId, LiveTypes, Param, Resolve, Type, TypeDef,
+        TypeDefKind, TypeOwner, WorldId, WorldKey,
     },
 };
 
 pub(crate) const ASYNC_CORE_DIR: &str = "async-core";
+pub(crate) const FFI_DIR: &str = "ffi";
 
 #[derive(Default)]
 pub(crate) struct Imports {
@@ -53,14 +54,14 @@ impl PkgResolver {
 
     pub(crate) fn qualifier(&mut self, this: &str, ty: &TypeDef) -> String {
         if let TypeOwner::Interface(id) = &ty.owner {
-            if let Some(name) = self.export_interface_names.get(id) {
-                if name != this {
-                    return self.qualify_package(this, &name.clone());
-                }
-            } else if let Some(name) = self.import_interface_names.get(id) {
-                if name != this {
-                    return self.qualify_package(this, &name.clone());
-                }
+            let name = self
+                .shared_import_package(ty)
+                .or_else(|| self.export_interface_names.get(id))
+                .or_else(|| self.import_interface_names.get(id));
+            if let Some(name) = name
+                && name != this
+            {
+                return self.qualify_package(this, &name.clone());
             }
         } else if let TypeOwner::World(id) = &ty.owner {
             let name = PkgResolver::world_name(&self.resolve, *id);
@@ -72,6 +73,35 @@ impl PkgResolver {
         String::new()
     }
 
+    /// Returns the package of the imported interface defining `ty` if the
+    /// exported side of the same interface can reuse that definition.
+    ///
+    /// An interface that is both imported and exported is cloned for its
+    /// export, so the clone is traced back to the imported original. Types
+    /// reaching a resource can't be shared, since an imported resource and an
+    /// exported resource are distinct types.
+    pub(crate) fn shared_import_package(&self, ty: &TypeDef) -> Option<&String> {
+        let TypeOwner::Interface(interface) = ty.owner else {
+            return None;
+        };
+        let original = self.resolve.interfaces[interface]
+            .clone_of
+            .unwrap_or(interface);
+        let package = self.import_interface_names.get(&original)?;
+        let id = *self.resolve.interfaces[interface]
+            .types
+            .get(ty.name.as_ref()?)?;
+        let mut live = LiveTypes::default();
+        live.add_type_id(&self.resolve, id);
+        let has_resource = live.iter().any(|id| {
+            matches!(
+                self.resolve.types[id].kind,
+                TypeDefKind::Resource | TypeDefKind::Handle(_)
+            )
+        });
+        (!has_resource).then_some(package)
+    }
+
     pub(crate) fn func_call(
         &mut self,
         this: &str,
@@ -117,7 +147,7 @@ impl PkgResolver {
 
     pub(crate) fn type_constructor(&mut self, this: &str, ty: &Type) -> String {
         match ty {
-            Type::ErrorContext => unimplemented!("moonbit error context type name"),
+            Type::ErrorContext => self.type_name(this, ty),
             Type::Id(id) => {
                 let ty = self.resolve.types[dealias(&self.resolve, *id)].clone();
                 match ty.kind {
@@ -184,7 +214,10 @@ impl PkgResolver {
             Type::F32 => "Float".into(),
             Type::F64 => "Double".into(),
             Type::String => "String".into(),
-            Type::ErrorContext => todo!("moonbit error context type name"),
+            Type::ErrorContext => {
+                let qualifier = self.qualify_package(this, FFI_DIR);
+                format!("{qualifier}ErrorContext")
+            }
             Type::Id(id) => {
                 let ty = self.resolve.types[dealias(&self.resolve, *id)].clone();
                 match ty.kind {
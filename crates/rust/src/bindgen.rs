@@ -827,6 +827,7 @@ impl Bindgen for FunctionBindgen<'_, '_> {
                 element,
                 size: _,
                 id: _,
+                offset: _,
             } => {
                 let body = self.blocks.pop().unwrap();
                 let vec = operands[0].clone();
@@ -1384,6 +1385,7 @@ impl Bindgen for FunctionBindgen<'_, '_> {
                 element,
                 size,
                 id: _,
+                offset: _,
             } => {
                 let body = self.blocks.pop().unwrap();
                 let elemsize = self
@@ -2,7 +2,7 @@ use crate::config::StringList;
 use crate::{Compile, LanguageMethods, Runner, Verify};
 use anyhow::{Context, Result};
 use clap::Parser;
-use heck::ToSnakeCase;
+use heck::{ToShoutySnakeCase, ToSnakeCase};
 use serde::Deserialize;
 use std::env;
 use std::path::PathBuf;
@@ -65,6 +65,13 @@ impl LanguageMethods for C {
             ("no-sig-flattening", &["--no-sig-flattening"]),
             ("autodrop", &["--autodrop-borrows=yes"]),
             ("async", &["--async=all"]),
+            ("single-header", &["--single-header"]),
+            ("split-interfaces", &["--split-interfaces"]),
+            ("component-type-c", &["--component-type-format=c"]),
+            (
+                "component-type-assembly",
+                &["--component-type-format=assembly"],
+            ),
         ]
     }
 
@@ -171,22 +178,53 @@ fn produces_component(runner: &Runner) -> bool {
 }
 
 fn verify(runner: &Runner, verify: &Verify<'_>, compiler: PathBuf) -> Result<()> {
-    let mut cmd = Command::new(compiler);
-    cmd.arg(
-        verify
-            .bindings_dir
-            .join(format!("{}.c", verify.world.to_snake_case())),
-    )
-    .arg("-I")
-    .arg(&verify.bindings_dir)
-    .arg("-Wall")
-    .arg("-Wextra")
-    .arg("-Werror")
-    .arg("-Wc++-compat")
-    .arg("-Wno-unused-parameter")
-    .arg("-c")
-    .arg("-o")
-    .arg(verify.artifacts_dir.join("tmp.o"));
+    let snake = verify.world.to_snake_case();
+    let source = if verify.args.iter().any(|arg| arg == "--single-header") {
+        // Single-header bindings have no `*.c` file, so instantiate the
+        // implementation from a small translation unit instead.
+        let source = verify.artifacts_dir.join("impl.c");
+        super::write_if_different(
+            &source,
+            &format!(
+                "#define {}_IMPLEMENTATION\n#include \"{snake}.h\"\n",
+                verify.world.to_shouty_snake_case(),
+            ),
+        )?;
+        source
+    } else {
+        verify.bindings_dir.join(format!("{snake}.c"))
+    };
+    let mut cmd = Command::new(&compiler);
+    cmd.arg(source)
+        .arg("-I")
+        .arg(&verify.bindings_dir)
+        .arg("-Wall")
+        .arg("-Wextra")
+        .arg("-Werror")
+        .arg("-Wc++-compat")
+        .arg("-Wno-unused-parameter")
+        .arg("-c")
+        .arg("-o")
+        .arg(verify.artifacts_dir.join("tmp.o"));
     runner.run_command(&mut cmd)?;
+
+    // Also build the component type if it was emitted in a textual format.
+    for ext in ["c", "s"] {
+        let component_type = verify
+            .bindings_dir
+            .join(format!("{snake}_component_type.{ext}"));
+        if !component_type.exists() {
+            continue;
+        }
+        let mut cmd = Command::new(&compiler);
+        cmd.arg(component_type)
+            .arg("-Wall")
+            .arg("-Wextra")
+            .arg("-Werror")
+            .arg("-c")
+            .arg("-o")
+            .arg(verify.artifacts_dir.join(format!("component_type_{ext}.o")));
+        runner.run_command(&mut cmd)?;
+    }
     Ok(())
 }
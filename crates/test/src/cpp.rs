@@ -43,17 +43,23 @@ impl LanguageMethods for Cpp {
         &self,
         _runner: &Runner,
         name: &str,
-        config: &crate::config::WitConfig,
+        _config: &crate::config::WitConfig,
         _args: &[String],
     ) -> bool {
-        // Compiles on C++ despite the blanket async exclusion below.
-        if name == "issue-1598.wit" {
-            return false;
-        }
-        return match name {
-            "issue1514-6.wit" | "named-fixed-length-list.wit" => true,
-            _ => false,
-        } || config.async_;
+        // `error-context` is not supported yet, and the generator rejects
+        // async functions using fixed-length lists as unsupported.
+        name.starts_with("issue1514-6.wit")
+            || name.starts_with("error-context.wit")
+            || name == "named-fixed-length-list.wit-async"
+    }
+
+    fn codegen_test_variants(&self) -> &[(&str, &[&str])] {
+        &[
+            ("async", &["--async=all"]),
+            ("exceptions", &["--error-style=exceptions"]),
+            ("std-types", &["--std-types"]),
+            ("slab", &["--resource-table=slab"]),
+        ]
     }
 
     fn prepare(&self, runner: &mut Runner) -> anyhow::Result<()> {
@@ -189,6 +195,13 @@ impl LanguageMethods for Cpp {
 
         let compiler = clangpp(runner);
         let mut cmd = Command::new(compiler);
+        if verify
+            .args
+            .iter()
+            .any(|arg| arg == "--error-style=exceptions")
+        {
+            cmd.arg("-fwasm-exceptions");
+        }
         cmd.arg(
             verify
                 .bindings_dir
@@ -34,6 +34,10 @@ impl LanguageMethods for Csharp {
         &["--generate-stub"]
     }
 
+    fn codegen_test_variants(&self) -> &[(&str, &[&str])] {
+        &[("nullable", &["--nullable"])]
+    }
+
     fn should_fail_verify(
         &self,
         _runner: &Runner,
@@ -49,7 +53,6 @@ impl LanguageMethods for Csharp {
                 | "async-resource-func.wit"
                 | "import-export-resource.wit"
                 | "issue-1433.wit"
-                | "named-fixed-length-list.wit"
         )
     }
 
@@ -97,14 +100,8 @@ impl LanguageMethods for Csharp {
             .arg("-o")
             .arg(&out_wasm);
 
-        let os = match std::env::consts::OS {
-            "windows" => "win",
-            "linux" => std::env::consts::OS,
-            other => todo!("OS {} not supported", other),
-        };
-
         // TODO: Workaround for no aarch64(arm64 in dotnet parlance) packages on Windows
-        if os == "win" && std::env::consts::ARCH == "aarch64" {
+        if cfg!(windows) && std::env::consts::ARCH == "aarch64" {
             cmd.arg("/p:_hostArchitecture=x64");
         }
 
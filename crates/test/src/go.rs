@@ -4,6 +4,7 @@ use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::OnceLock;
 
 pub struct Go;
 
@@ -36,15 +37,9 @@ impl LanguageMethods for Go {
         &self,
         runner: &Runner,
         name: &str,
-        config: &crate::config::WitConfig,
+        _config: &crate::config::WitConfig,
         _args: &[String],
     ) -> bool {
-        if config.error_context {
-            return true;
-        }
-        if name == "named-fixed-length-list.wit" {
-            return true;
-        }
         if !runner.go_async_supported() {
             return name == "async-trait-function.wit" || name == "issue-1598.wit";
         }
@@ -77,6 +72,36 @@ impl LanguageMethods for Go {
         &["--generate-stubs"]
     }
 
+    fn codegen_test_variants(&self) -> &[(&str, &[&str])] {
+        const VARIANTS: &[(&str, &[&str])] = &[
+            (
+                "vendor-runtime",
+                &[concat!(
+                    "--vendor-runtime=",
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../go/src/pkg"
+                )],
+            ),
+            ("results-as-errors", &["--results-as-errors"]),
+            ("async-context", &["--async=all", "--async-context"]),
+            ("value-methods", &["--generate-value-methods"]),
+        ];
+        // Vendoring copies the packages out of the `go-pkg` submodule, which
+        // may not be checked out.
+        static WITHOUT_VENDORING: OnceLock<Vec<(&str, &[&str])>> = OnceLock::new();
+        if go_package_checked_out() {
+            VARIANTS
+        } else {
+            WITHOUT_VENDORING.get_or_init(|| {
+                VARIANTS
+                    .iter()
+                    .filter(|(name, _)| *name != "vendor-runtime")
+                    .copied()
+                    .collect()
+            })
+        }
+    }
+
     fn prepare(&self, runner: &mut Runner) -> Result<()> {
         let cwd = env::current_dir()?;
         let dir = cwd.join(&runner.opts.artifacts).join("go");
@@ -243,10 +268,33 @@ fn all_paths(path: &Path) -> Result<Vec<PathBuf>> {
     Ok(paths)
 }
 
-fn replace_bindings_go_mod(runner: &Runner, bindings_dir: &Path) -> Result<()> {
+/// Returns the path of the `go-pkg` submodule.
+fn go_package_path() -> PathBuf {
     let test_crate = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     let wit_bindgen_root = test_crate.parent().unwrap().parent().unwrap();
-    let go_package_path = wit_bindgen_root.join("crates/go/src/pkg");
+    wit_bindgen_root.join("crates/go/src/pkg")
+}
+
+/// Returns whether the `go-pkg` submodule is checked out, printing a note
+/// the first time it isn't.
+fn go_package_checked_out() -> bool {
+    static CHECKED_OUT: OnceLock<bool> = OnceLock::new();
+    *CHECKED_OUT.get_or_init(|| {
+        let path = go_package_path();
+        let checked_out = path.join("wit").is_dir();
+        if !checked_out {
+            println!(
+                "Skipping the Go `vendor-runtime` codegen tests: `{}` is empty, \
+                 run `git submodule update --init crates/go/src/pkg` to enable them",
+                path.display()
+            );
+        }
+        checked_out
+    })
+}
+
+fn replace_bindings_go_mod(runner: &Runner, bindings_dir: &Path) -> Result<()> {
+    let go_package_path = go_package_path();
 
     super::write_if_different(
         &bindings_dir.join("go.mod"),
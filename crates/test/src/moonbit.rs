@@ -126,10 +126,10 @@ impl LanguageMethods for MoonBit {
         &self,
         _runner: &Runner,
         name: &str,
-        config: &crate::config::WitConfig,
+        _config: &crate::config::WitConfig,
         _args: &[String],
     ) -> bool {
-        name == "named-fixed-length-list.wit-async" || config.error_context
+        name == "named-fixed-length-list.wit-async"
     }
 
     fn verify(&self, runner: &Runner, verify: &crate::Verify) -> anyhow::Result<()> {
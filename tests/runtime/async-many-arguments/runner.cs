@@ -0,0 +1,29 @@
+//@ wasmtime-flags = '-Wcomponent-model-async'
+
+using System.Diagnostics;
+using RunnerWorld.wit.Imports.test.asyncManyArguments;
+
+public class RunnerWorldExportsImpl
+{
+    public static async Task Run()
+    {
+        var sum = await IIImports.ManyArguments(1, 2, 3, 4, 5, 6, 7, 8, "spilled", new byte[] { 9, 10, 11 });
+        Debug.Assert(sum == 36);
+
+        await IIImports.ManyArgumentsNoResult(8, 7, 6, 5, 4, 3, 2, 1);
+
+        // With backpressure set the subtask stays in the starting state, so the
+        // spilled arguments are only read after the call has returned.
+        IIImports.BackpressureSet(true);
+        var starting = IIImports.ManyArguments(1, 2, 3, 4, 5, 6, 7, 8, "spilled", new Memory<byte>(new byte[] { 9, 10, 11 }));
+        Debug.Assert(!starting.IsCompleted);
+        GC.Collect();
+        IIImports.BackpressureSet(false);
+        Debug.Assert(await starting == 36);
+    }
+
+    public static int RunCallback()
+    {
+        throw new NotImplementedException();
+    }
+}
@@ -0,0 +1,54 @@
+//@ wasmtime-flags = '-Wcomponent-model-async'
+
+include!(env!("BINDINGS"));
+
+use crate::test::async_many_arguments::i::*;
+use futures::task::noop_waker_ref;
+use std::future::Future;
+use std::task::Context;
+
+struct Component;
+
+export!(Component);
+
+impl Guest for Component {
+    async fn run() {
+        let sum = many_arguments(
+            1,
+            2,
+            3,
+            4,
+            5,
+            6,
+            7,
+            8,
+            "spilled".to_string(),
+            vec![9, 10, 11],
+        )
+        .await;
+        assert_eq!(sum, 36);
+        many_arguments_no_result(8, 7, 6, 5, 4, 3, 2, 1).await;
+
+        // With backpressure set the subtask stays in the "starting" state, so
+        // the spilled arguments are only read after the call has returned.
+        backpressure_set(true);
+        let mut starting = Box::pin(many_arguments(
+            1,
+            2,
+            3,
+            4,
+            5,
+            6,
+            7,
+            8,
+            "spilled".to_string(),
+            vec![9, 10, 11],
+        ));
+        assert!(starting
+            .as_mut()
+            .poll(&mut Context::from_waker(noop_waker_ref()))
+            .is_pending());
+        backpressure_set(false);
+        assert_eq!(starting.await, 36);
+    }
+}
@@ -0,0 +1,53 @@
+include!(env!("BINDINGS"));
+
+struct Component;
+
+export!(Component);
+
+impl crate::exports::test::async_many_arguments::i::Guest for Component {
+    async fn many_arguments(
+        a1: u64,
+        a2: u64,
+        a3: u64,
+        a4: u64,
+        a5: u64,
+        a6: u64,
+        a7: u64,
+        a8: u64,
+        name: String,
+        bytes: Vec<u8>,
+    ) -> u64 {
+        assert_eq!(a1, 1);
+        assert_eq!(a2, 2);
+        assert_eq!(a3, 3);
+        assert_eq!(a4, 4);
+        assert_eq!(a5, 5);
+        assert_eq!(a6, 6);
+        assert_eq!(a7, 7);
+        assert_eq!(a8, 8);
+        assert_eq!(name, "spilled");
+        assert_eq!(bytes, [9, 10, 11]);
+        a1 + a2 + a3 + a4 + a5 + a6 + a7 + a8
+    }
+
+    async fn many_arguments_no_result(
+        a1: u32,
+        a2: u32,
+        a3: u32,
+        a4: u32,
+        a5: u32,
+        a6: u32,
+        a7: u32,
+        a8: u32,
+    ) {
+        assert_eq!([a1, a2, a3, a4, a5, a6, a7, a8], [8, 7, 6, 5, 4, 3, 2, 1]);
+    }
+
+    fn backpressure_set(x: bool) {
+        if x {
+            wit_bindgen::backpressure_inc();
+        } else {
+            wit_bindgen::backpressure_dec();
+        }
+    }
+}
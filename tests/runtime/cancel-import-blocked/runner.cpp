@@ -0,0 +1,47 @@
+//@ wasmtime-flags = '-Wcomponent-model-async -Wcomponent-model-async-builtins'
+
+#define WIT_ASYNC_SUBTASK_CANCEL
+#include <assert.h>
+#include <runner_cpp.h>
+
+wit::task<> exports::runner::Run() {
+  auto [writer, reader] = wit::future_new<wit::Void>();
+  auto [ack_writer, ack_reader] = wit::future_new<wit::Void>();
+  {
+    auto call = my::test::i::SlowCancel(std::move(reader), std::move(ack_reader));
+    call.start();
+    assert(!call.done());
+    assert(!wit::EventLoop::empty());
+  }
+
+  // The callee doesn't acknowledge the cancellation before `ack` was
+  // written, so the cancelled subtask is still pending.
+  assert(!wit::EventLoop::empty());
+
+  bool written = co_await std::move(ack_writer).async_write(wit::Void{});
+  assert(written);
+
+  // The callee dropped `x` when it was cancelled.
+  written = co_await std::move(writer).async_write(wit::Void{});
+  assert(!written);
+
+  // A callee which returns instead of acknowledging the cancellation still
+  // reads the arguments and writes the result after the call was dropped.
+  {
+    auto [writer, reader] = wit::future_new<wit::Void>();
+    auto [ack_writer, ack_reader] = wit::future_new<wit::Void>();
+    {
+      auto call = my::test::i::SlowReturn(std::move(reader), std::move(ack_reader),
+                                          wit::string::from_view("abc"),
+                                          wit::string::from_view("de"));
+      call.start();
+      assert(!call.done());
+    }
+    assert(!wit::EventLoop::empty());
+
+    bool written = co_await std::move(ack_writer).async_write(wit::Void{});
+    assert(written);
+    written = co_await std::move(writer).async_write(wit::Void{});
+    assert(!written);
+  }
+}
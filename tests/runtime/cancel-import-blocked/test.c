@@ -0,0 +1,112 @@
+//@ args = '--rename my:test/i=test'
+
+#include <assert.h>
+#include <stdbool.h>
+#include <stdlib.h>
+#include <test.h>
+
+struct my_task {
+  test_waitable_set_t set;
+  exports_test_future_void_t x;
+  exports_test_future_void_t ack;
+  // Whether the task returns `len` instead of acknowledging the cancellation.
+  bool returns;
+  uint32_t len;
+};
+
+#ifdef __wasm_libcall_thread_context__
+static _Thread_local struct my_task *current_task = NULL;
+
+static void set_task(struct my_task *task) {
+  current_task = task;
+}
+
+static struct my_task *get_task(void) {
+  return current_task;
+}
+#else
+static void set_task(struct my_task *task) {
+  test_context_set_0(task);
+}
+
+static struct my_task *get_task(void) {
+  return (struct my_task*) test_context_get_0();
+}
+#endif
+
+static test_callback_code_t start(exports_test_future_void_t x, exports_test_future_void_t ack, bool returns, uint32_t len) {
+  struct my_task *task = (struct my_task*) malloc(sizeof(struct my_task));
+  assert(task != NULL);
+  test_waitable_status_t status = exports_test_future_void_read(x);
+  assert(status == TEST_WAITABLE_STATUS_BLOCKED);
+  task->x = x;
+  task->ack = ack;
+  task->returns = returns;
+  task->len = len;
+  task->set = test_waitable_set_new();
+  test_waitable_join(task->x, task->set);
+
+  assert(get_task() == NULL);
+  set_task(task);
+  return TEST_CALLBACK_CODE_WAIT(task->set);
+}
+
+test_callback_code_t exports_test_slow_cancel(exports_test_future_void_t x, exports_test_future_void_t ack) {
+  return start(x, ack, false, 0);
+}
+
+test_callback_code_t exports_test_slow_return(exports_test_future_void_t x, exports_test_future_void_t ack, test_string_t *a, test_string_t *b) {
+  uint32_t len = a->len + b->len;
+  test_string_free(a);
+  test_string_free(b);
+  return start(x, ack, true, len);
+}
+
+static test_callback_code_t callback(test_event_t *event) {
+  struct my_task *task = get_task();
+  set_task(NULL);
+  if (event->event == TEST_EVENT_CANCEL) {
+    assert(event->waitable == 0);
+    assert(event->code == 0);
+
+    test_waitable_join(task->x, 0);
+    test_waitable_status_t status = exports_test_future_void_cancel_read(task->x);
+    assert(TEST_WAITABLE_STATE(status) == TEST_WAITABLE_CANCELLED);
+    assert(TEST_WAITABLE_COUNT(status) == 0);
+    exports_test_future_void_drop_readable(task->x);
+
+    // Hold off acknowledging the cancellation until `ack` is written.
+    status = exports_test_future_void_read(task->ack);
+    assert(status == TEST_WAITABLE_STATUS_BLOCKED);
+    test_waitable_join(task->ack, task->set);
+    set_task(task);
+    return TEST_CALLBACK_CODE_WAIT(task->set);
+  }
+
+  assert(event->event == TEST_EVENT_FUTURE_READ);
+  assert(event->waitable == task->ack);
+  assert(TEST_WAITABLE_STATE(event->code) == TEST_WAITABLE_COMPLETED);
+  assert(TEST_WAITABLE_COUNT(event->code) == 0);
+  test_waitable_join(task->ack, 0);
+  exports_test_future_void_drop_readable(task->ack);
+  test_waitable_set_drop(task->set);
+  bool returns = task->returns;
+  uint32_t len = task->len;
+  free(task);
+
+  if (returns) {
+    assert(len == 5);
+    exports_test_slow_return_return(len);
+  } else {
+    test_task_cancel();
+  }
+  return TEST_CALLBACK_CODE_EXIT;
+}
+
+test_callback_code_t exports_test_slow_cancel_callback(test_event_t *event) {
+  return callback(event);
+}
+
+test_callback_code_t exports_test_slow_return_callback(test_event_t *event) {
+  return callback(event);
+}
@@ -0,0 +1,46 @@
+//@ wasmtime-flags = '-Wcomponent-model-async'
+
+#include <assert.h>
+#include <runner_cpp.h>
+
+wit::task<> exports::runner::Run() {
+  // Call an import and cancel it by destroying its task.
+  {
+    auto [writer, reader] = wit::future_new<wit::Void>();
+    {
+      auto call = my::test::i::PendingImport(std::move(reader));
+      call.start();
+      assert(!call.done());
+    }
+
+    // The export dropped the readable end when it was cancelled.
+    bool written = co_await std::move(writer).async_write(wit::Void{});
+    assert(!written);
+  }
+
+  // One import in "started", one in "starting", then cancel both.
+  {
+    auto [writer1, reader1] = wit::future_new<wit::Void>();
+    auto [writer2, reader2] = wit::future_new<wit::Void>();
+    {
+      auto call1 = my::test::i::PendingImport(std::move(reader1));
+      call1.start();
+      assert(!call1.done());
+
+      // With backpressure set the second call can't start yet.
+      my::test::i::BackpressureSet(true);
+      auto call2 = my::test::i::PendingImport(std::move(reader2));
+      call2.start();
+      assert(!call2.done());
+    }
+
+    // The second subtask never started, so its task dropped `reader2`
+    // instead of handing it over.
+    bool written = co_await std::move(writer1).async_write(wit::Void{});
+    assert(!written);
+    written = co_await std::move(writer2).async_write(wit::Void{});
+    assert(!written);
+
+    my::test::i::BackpressureSet(false);
+  }
+}
@@ -0,0 +1,81 @@
+//@ wasmtime-flags = '-Wcomponent-model-async -Wcomponent-model-async-builtins'
+//@ args = '--async-context'
+
+package export_wit_world
+
+import (
+	"context"
+	test "wit_component/my_test_i"
+
+	witAsync "go.bytecodealliance.org/pkg/wit/async"
+	witTypes "go.bytecodealliance.org/pkg/wit/types"
+)
+
+func Run() {
+	// cancelling an import in progress
+	{
+		tx, rx := test.MakeFutureUnit()
+		ctx, cancel := context.WithCancel(context.Background())
+		result := make(chan error)
+		go func() {
+			result <- test.PendingImportContext(ctx, rx)
+		}()
+		for range 5 {
+			witAsync.Yield()
+		}
+		cancel()
+		assert(<-result == context.Canceled)
+		assert(!tx.Write(witTypes.Unit{}))
+	}
+
+	// cancelling an import with an already cancelled context
+	{
+		tx, rx := test.MakeFutureUnit()
+		ctx, cancel := context.WithCancel(context.Background())
+		cancel()
+		assert(test.PendingImportContext(ctx, rx) == context.Canceled)
+		assert(!tx.Write(witTypes.Unit{}))
+	}
+
+	// cancelling an import before it starts hands its arguments back
+	{
+		tx, rx := test.MakeFutureUnit()
+		test.BackpressureSet(true)
+		ctx, cancel := context.WithCancel(context.Background())
+		result := make(chan error)
+		go func() {
+			result <- test.PendingImportContext(ctx, rx)
+		}()
+		for range 5 {
+			witAsync.Yield()
+		}
+		cancel()
+		assert(<-result == context.Canceled)
+		test.BackpressureSet(false)
+
+		// `rx` is still ours, so it can be passed to another call
+		write := make(chan bool)
+		go func() {
+			write <- tx.Write(witTypes.Unit{})
+		}()
+		assert(test.PendingImportContext(context.Background(), rx) == nil)
+		assert(<-write)
+	}
+
+	// letting an import complete
+	{
+		tx, rx := test.MakeFutureUnit()
+		write := make(chan bool)
+		go func() {
+			write <- tx.Write(witTypes.Unit{})
+		}()
+		assert(test.PendingImportContext(context.Background(), rx) == nil)
+		assert(<-write)
+	}
+}
+
+func assert(v bool) {
+	if !v {
+		panic("assertion failed")
+	}
+}
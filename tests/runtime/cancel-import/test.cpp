@@ -0,0 +1,17 @@
+#include <assert.h>
+#include <test_cpp.h>
+
+wit::task<> exports::my::test::i::PendingImport(wit::FutureReader<wit::Void> x) {
+  // Only returns if the caller writes the future, a cancelled call is
+  // destroyed while waiting here, which cancels the read.
+  std::optional<wit::Void> value = co_await std::move(x);
+  assert(value.has_value());
+}
+
+void exports::my::test::i::BackpressureSet(bool x) {
+  if (x) {
+    wit::backpressure_inc();
+  } else {
+    wit::backpressure_dec();
+  }
+}
@@ -0,0 +1,97 @@
+//@ wasmtime-flags = '-Wcomponent-model-async'
+
+#include <assert.h>
+#include <runner_cpp.h>
+
+namespace i = my::test::i;
+
+/// Reads `reader` until the writer dropped it
+template <class T> static wit::task<std::vector<T>> read_all(wit::StreamReader<T> reader) {
+  std::vector<T> values;
+  for (;;) {
+    auto [items, state] = co_await reader.async_read(16);
+    for (auto &item : items) {
+      values.push_back(std::move(item));
+    }
+    if (state == wit::WaitableState::kDropped) {
+      co_return values;
+    }
+  }
+}
+
+wit::task<> exports::runner::Run() {
+  // Futures and streams written here and read by the callee.
+  {
+    auto [writer, reader] = wit::future_new<wit::string>();
+    auto call = i::ReadFuture(std::move(reader));
+    call.start();
+    bool written = co_await std::move(writer).async_write(wit::string::from_view("hello"));
+    assert(written);
+    wit::string value = co_await call;
+    assert(value.get_view() == "hello");
+  }
+  {
+    auto [writer, reader] = wit::future_new<wit::Void>();
+    auto call = i::ReadUnitFuture(std::move(reader));
+    call.start();
+    bool written = co_await std::move(writer).async_write(wit::Void{});
+    assert(written);
+    co_await call;
+  }
+  {
+    auto [writer, reader] = wit::stream_new<wit::string>();
+    auto call = i::ReadStream(std::move(reader));
+    call.start();
+    std::vector<wit::string> items;
+    items.push_back(wit::string::from_view("a"));
+    items.push_back(wit::string::from_view("bc"));
+    auto [remaining, state] = co_await writer.async_write(std::move(items));
+    assert(remaining.empty());
+    assert(state == wit::WaitableState::kCompleted);
+    writer.reset();
+    wit::vector<wit::string> values = co_await call;
+    assert(values.size() == 2);
+    assert(values[0].get_view() == "a");
+    assert(values[1].get_view() == "bc");
+  }
+  {
+    auto [writer, reader] = wit::stream_new<wit::Void>();
+    auto call = i::ReadUnitStream(std::move(reader));
+    call.start();
+    auto [remaining, state] = co_await writer.async_write(std::vector<wit::Void>(3));
+    assert(remaining.empty());
+    assert(state == wit::WaitableState::kCompleted);
+    writer.reset();
+    uint32_t count = co_await call;
+    assert(count == 3);
+  }
+
+  // Futures and streams written by the callee and read here.
+  {
+    wit::FutureReader<wit::string> reader = co_await i::WriteFuture(wit::string::from_view("world"));
+    std::optional<wit::string> value = co_await std::move(reader);
+    assert(value.has_value());
+    assert(value->get_view() == "world");
+  }
+  {
+    wit::FutureReader<wit::Void> reader = co_await i::WriteUnitFuture();
+    std::optional<wit::Void> value = co_await std::move(reader);
+    assert(value.has_value());
+  }
+  {
+    std::vector<wit::string> items;
+    items.push_back(wit::string::from_view("x"));
+    items.push_back(wit::string::from_view("yz"));
+    wit::StreamReader<wit::string> reader = co_await i::WriteStream(
+        wit::vector<wit::string>::from_view(std::span<wit::string>(items)));
+    std::vector<wit::string> values = co_await read_all(std::move(reader));
+    assert(values.size() == 2);
+    assert(values[0].get_view() == "x");
+    assert(values[1].get_view() == "yz");
+  }
+  {
+    wit::StreamReader<wit::Void> reader = co_await i::WriteUnitStream(4);
+    std::vector<wit::Void> values = co_await read_all(std::move(reader));
+    assert(values.size() == 4);
+  }
+}
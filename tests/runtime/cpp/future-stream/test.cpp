@@ -0,0 +1,84 @@
+#include <assert.h>
+#include <memory>
+#include <test_cpp.h>
+
+namespace i = exports::my::test::i;
+
+wit::task<wit::string> i::ReadFuture(wit::FutureReader<wit::string> x) {
+  std::optional<wit::string> value = co_await std::move(x);
+  assert(value.has_value());
+  co_return std::move(*value);
+}
+
+wit::task<> i::ReadUnitFuture(wit::FutureReader<wit::Void> x) {
+  std::optional<wit::Void> value = co_await std::move(x);
+  assert(value.has_value());
+}
+
+wit::task<wit::vector<wit::string>> i::ReadStream(wit::StreamReader<wit::string> x) {
+  std::vector<wit::string> values;
+  for (;;) {
+    auto [items, state] = co_await x.async_read(16);
+    for (auto &item : items) {
+      values.push_back(std::move(item));
+    }
+    if (state == wit::WaitableState::kDropped) {
+      break;
+    }
+  }
+  co_return wit::vector<wit::string>::from_view(std::span<wit::string>(values));
+}
+
+wit::task<uint32_t> i::ReadUnitStream(wit::StreamReader<wit::Void> x) {
+  uint32_t count = 0;
+  for (;;) {
+    auto [items, state] = co_await x.async_read(16);
+    count += items.size();
+    if (state == wit::WaitableState::kDropped) {
+      co_return count;
+    }
+  }
+}
+
+// The writes below are still pending when the function returns, the export
+// task keeps running until the caller read the values.
+
+wit::task<wit::FutureReader<wit::string>> i::WriteFuture(wit::string x) {
+  auto [writer, reader] = wit::future_new<wit::string>();
+  std::move(writer).write(std::move(x), [](bool written) { assert(written); });
+  co_return std::move(reader);
+}
+
+wit::task<wit::FutureReader<wit::Void>> i::WriteUnitFuture() {
+  auto [writer, reader] = wit::future_new<wit::Void>();
+  std::move(writer).write(wit::Void{}, [](bool written) { assert(written); });
+  co_return std::move(reader);
+}
+
+/// Writes `items` to `writer`, then drops it to end the stream
+template <class T>
+static void write_all(wit::StreamWriter<T> writer, std::vector<T> items) {
+  auto shared = std::make_shared<wit::StreamWriter<T>>(std::move(writer));
+  shared->write(std::move(items),
+                [shared](std::vector<T> remaining, wit::WaitableState state) {
+                  assert(remaining.empty());
+                  assert(state == wit::WaitableState::kCompleted);
+                  shared->reset();
+                });
+}
+
+wit::task<wit::StreamReader<wit::string>> i::WriteStream(wit::vector<wit::string> x) {
+  auto [writer, reader] = wit::stream_new<wit::string>();
+  std::vector<wit::string> items;
+  for (auto &item : x.get_view()) {
+    items.push_back(std::move(item));
+  }
+  write_all(std::move(writer), std::move(items));
+  co_return std::move(reader);
+}
+
+wit::task<wit::StreamReader<wit::Void>> i::WriteUnitStream(uint32_t count) {
+  auto [writer, reader] = wit::stream_new<wit::Void>();
+  write_all(std::move(writer), std::vector<wit::Void>(count));
+  co_return std::move(reader);
+}
@@ -0,0 +1,38 @@
+//@ args = '--nullable'
+
+using System.Diagnostics;
+using RunnerWorld.wit.Imports.my.test;
+
+namespace RunnerWorld;
+
+public class RunnerWorldExportsImpl : IRunnerWorldExports
+{
+    public static void Run()
+    {
+        // Only the `option<T>` fields may be left out of an object initializer.
+        var anonymous = IIImports.RoundtripPerson(new IIImports.Person
+        {
+            name = "Ada",
+            rating = Option<uint?>.None,
+        });
+        Debug.Assert(anonymous.name == "Ada");
+        Debug.Assert(anonymous.nickname == null);
+        Debug.Assert(anonymous.age == null);
+        Debug.Assert(!anonymous.rating.HasValue);
+
+        var known = IIImports.RoundtripPerson(new IIImports.Person
+        {
+            name = "Grace",
+            nickname = "Amazing Grace",
+            age = 85,
+            rating = new Option<uint?>(5),
+        });
+        Debug.Assert(known.nickname == "Amazing Grace");
+        Debug.Assert(known.age == 85);
+        Debug.Assert(known.rating.Value == 5);
+
+        // Records are immutable, but `with` expressions still work.
+        var renamed = known with { name = "Grace Hopper" };
+        Debug.Assert(IIImports.RoundtripPerson(renamed).name == "Grace Hopper");
+    }
+}
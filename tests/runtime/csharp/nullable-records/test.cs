@@ -0,0 +1,18 @@
+//@ args = '--nullable'
+
+namespace TestWorld.wit.Exports.my.test
+{
+    public class IExportsImpl : IIExports
+    {
+        public static IIExports.Person RoundtripPerson(IIExports.Person p)
+        {
+            return new IIExports.Person
+            {
+                name = p.name,
+                nickname = p.nickname,
+                age = p.age,
+                rating = p.rating,
+            };
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//@ wasmtime-flags = '-Wcomponent-model-async'
+
+using System.Diagnostics;
+using System.Threading.Channels;
+using RunnerWorld;
+using RunnerWorld.wit.Imports.my.test;
+
+public class RunnerWorldExportsImpl
+{
+    public static async Task Run()
+    {
+        // Stream written from an `IAsyncEnumerable<T>`.
+        {
+            var (reader, writer) = IIImports.StreamNewUint();
+            async Task Write()
+            {
+                await writer.WriteAllAsync(Numbers(10));
+                writer.Dispose();
+            }
+
+            var sum = IIImports.SumStream(reader);
+            await Write();
+            Debug.Assert(await sum == 55);
+        }
+
+        // Stream written from a `ChannelReader<T>`.
+        {
+            var (reader, writer) = IIImports.StreamNewUint();
+            var channel = Channel.CreateUnbounded<uint>();
+            async Task Write()
+            {
+                await writer.WriteAllAsync(channel.Reader);
+                writer.Dispose();
+            }
+
+            var sum = IIImports.SumStream(reader);
+            var write = Write();
+            for (uint i = 1; i <= 20; i++)
+            {
+                channel.Writer.TryWrite(i);
+            }
+            channel.Writer.Complete();
+            await write;
+            Debug.Assert(await sum == 210);
+        }
+
+        // Future read by awaiting the reader directly.
+        {
+            var (reader, writer) = IIImports.FutureNewUint();
+            var value = IIImports.ReadFuture(reader);
+            await writer.WriteAsync(42);
+            writer.Dispose();
+            Debug.Assert(await value == 42);
+        }
+
+        // A cancelled future read leaves the writer to observe the dropped reader.
+        {
+            var (reader, writer) = IIImports.FutureNewUint();
+            await IIImports.CancelFutureRead(reader);
+
+            bool dropped = false;
+            try
+            {
+                await writer.WriteAsync(1);
+            }
+            catch (StreamDroppedException)
+            {
+                dropped = true;
+            }
+            Debug.Assert(dropped);
+            writer.Dispose();
+        }
+
+        // A read cancelled after the value was written completes with the value.
+        {
+            var (dataReader, dataWriter) = IIImports.FutureNewUint();
+            var (signalReader, signalWriter) = IIImports.FutureNew();
+            var value = IIImports.CancelReadAfterWrite(dataReader, signalReader);
+            await signalWriter.Write();
+            await dataWriter.WriteAsync(7);
+            signalWriter.Dispose();
+            dataWriter.Dispose();
+            Debug.Assert(await value == 7);
+        }
+    }
+
+    static async IAsyncEnumerable<uint> Numbers(uint count)
+    {
+        for (uint i = 1; i <= count; i++)
+        {
+            await Task.CompletedTask;
+            yield return i;
+        }
+    }
+
+    public static int RunCallback()
+    {
+        throw new NotImplementedException();
+    }
+}
@@ -0,0 +1,62 @@
+using System.Diagnostics;
+
+namespace TestWorld.wit.Exports.my.test
+{
+    public class IExportsImpl : IIExports
+    {
+        public static async Task<uint> SumStream(StreamReader<uint> x)
+        {
+            x.BatchSize = 4;
+            uint sum = 0;
+            await foreach (var value in x)
+            {
+                sum += value;
+            }
+            x.Dispose();
+            return sum;
+        }
+
+        public static async Task<uint> ReadFuture(FutureReader<uint> x)
+        {
+            var value = await x;
+            x.Dispose();
+            return value;
+        }
+
+        public static async Task CancelFutureRead(FutureReader<uint> x)
+        {
+            using var cts = new CancellationTokenSource();
+            var read = x.ReadAsync(cts.Token);
+            Debug.Assert(!read.IsCompleted);
+
+            cts.Cancel();
+            bool cancelled = false;
+            try
+            {
+                await read;
+            }
+            catch (OperationCanceledException)
+            {
+                cancelled = true;
+            }
+            Debug.Assert(cancelled);
+            x.Dispose();
+        }
+
+        public static async Task<uint> CancelReadAfterWrite(FutureReader<uint> data, FutureReader signal)
+        {
+            using var cts = new CancellationTokenSource();
+            var read = data.ReadAsync(cts.Token);
+            Debug.Assert(!read.IsCompleted);
+
+            // `data` was written before `signal`, so the cancellation loses the race
+            // and the read still yields the value.
+            await signal;
+            cts.Cancel();
+            var value = await read;
+            data.Dispose();
+            signal.Dispose();
+            return value;
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//@ wasmtime-flags = '-Wcomponent-model-fixed-length-lists'
+
+#include <assert.h>
+#include <runner_cpp.h>
+
+void exports::runner::Run() {
+    using namespace ::test::fixed_length_lists::to_test;
+
+    ListParam({1, 2, 3, 4});
+    ListParam2({{{1, 2}, {3, 4}}});
+    ListParam3({-1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16, -17, 18, -19, 20});
+    {
+        auto result = ListResult();
+        assert((result == std::array<uint8_t, 8>{'0', '1', 'A', 'B', 'a', 'b', 128, 255}));
+    }
+    {
+        auto result = ListMinmax16({0, 1024, 32768, 65535}, {1, 2048, -32767, -2});
+        assert((std::get<0>(result) == std::array<uint16_t, 4>{0, 1024, 32768, 65535}));
+        assert((std::get<1>(result) == std::array<int16_t, 4>{1, 2048, -32767, -2}));
+    }
+    {
+        auto result = ListMinmaxFloat({2.0f, -42.0f}, {0.25, -0.125});
+        assert((std::get<0>(result) == std::array<float, 2>{2.0f, -42.0f}));
+        assert((std::get<1>(result) == std::array<double, 2>{0.25, -0.125}));
+    }
+    {
+        std::array<uint8_t, 12> value{'a', 'b', 'c', 'd', 0, 1, 2, 3, 'A', 'B', 'Y', 'Z'};
+        auto result = ListRoundtrip(value);
+        assert(result == value);
+    }
+    {
+        std::array<std::array<uint32_t, 2>, 2> a{{{1, 5}, {42, 1000000}}};
+        std::array<std::array<int32_t, 2>, 2> b{{{-1, 3}, {-2000000, 4711}}};
+        auto result = NestedRoundtrip(a, b);
+        assert(std::get<0>(result) == a);
+        assert(std::get<1>(result) == b);
+    }
+    {
+        std::array<std::array<uint32_t, 2>, 2> a{{{1, 5}, {42, 1000000}}};
+        std::array<std::array<int32_t, 4>, 4> b{{
+            {-1, 3, -2, 4},
+            {-2000000, 4711, 99999, -5},
+            {-6, 7, 8, -9},
+            {50, -5, 500, -5000},
+        }};
+        auto result = LargeRoundtrip(a, b);
+        assert(std::get<0>(result) == a);
+        assert(std::get<1>(result) == b);
+    }
+    {
+        auto result = NightmareOnCpp({Nested{{1, -1}}, Nested{{2, -2}}});
+        assert((result[0].l == std::array<int32_t, 2>{1, -1}));
+        assert((result[1].l == std::array<int32_t, 2>{2, -2}));
+    }
+}
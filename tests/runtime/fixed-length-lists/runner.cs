@@ -0,0 +1,106 @@
+//@ wasmtime-flags = '-Wcomponent-model-fixed-length-lists'
+
+using System.Diagnostics;
+using RunnerWorld.wit.Imports.test.fixedLengthLists;
+
+namespace RunnerWorld;
+
+public class RunnerWorldExportsImpl : IRunnerWorldExports
+{
+    public static void Run()
+    {
+        IToTestImports.ListParam(Make<FixedLengthList4<uint>, uint>([1, 2, 3, 4]));
+        IToTestImports.ListParam2(Make<FixedLengthList2<FixedLengthList2<uint>>, FixedLengthList2<uint>>([
+            Make<FixedLengthList2<uint>, uint>([1, 2]),
+            Make<FixedLengthList2<uint>, uint>([3, 4]),
+        ]));
+        IToTestImports.ListParam3(Make<FixedLengthList20<int>, int>(
+            [-1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16, -17, 18, -19, 20]));
+
+        {
+            var result = IToTestImports.ListResult();
+            Debug.Assert(((ReadOnlySpan<byte>)result).SequenceEqual(
+                [(byte)'0', (byte)'1', (byte)'A', (byte)'B', (byte)'a', (byte)'b', 128, 255]));
+        }
+        {
+            var (a, b) = IToTestImports.ListMinmax16(
+                Make<FixedLengthList4<ushort>, ushort>([0, 1024, 32768, 65535]),
+                Make<FixedLengthList4<short>, short>([1, 2048, -32767, -2]));
+            Debug.Assert(((ReadOnlySpan<ushort>)a).SequenceEqual([0, 1024, 32768, 65535]));
+            Debug.Assert(((ReadOnlySpan<short>)b).SequenceEqual([1, 2048, -32767, -2]));
+        }
+        {
+            var (a, b) = IToTestImports.ListMinmaxFloat(
+                Make<FixedLengthList2<float>, float>([2.0f, -42.0f]),
+                Make<FixedLengthList2<double>, double>([0.25, -0.125]));
+            Debug.Assert(((ReadOnlySpan<float>)a).SequenceEqual([2.0f, -42.0f]));
+            Debug.Assert(((ReadOnlySpan<double>)b).SequenceEqual([0.25, -0.125]));
+        }
+        {
+            byte[] expected = [(byte)'a', (byte)'b', (byte)'c', (byte)'d', 0, 1, 2, 3, (byte)'A', (byte)'B', (byte)'Y', (byte)'Z'];
+            var result = IToTestImports.ListRoundtrip(Make<FixedLengthList12<byte>, byte>(expected));
+            Debug.Assert(((ReadOnlySpan<byte>)result).SequenceEqual(expected));
+        }
+        {
+            var (a, b) = IToTestImports.NestedRoundtrip(
+                Make<FixedLengthList2<FixedLengthList2<uint>>, FixedLengthList2<uint>>([
+                    Make<FixedLengthList2<uint>, uint>([1, 5]),
+                    Make<FixedLengthList2<uint>, uint>([42, 1_000_000]),
+                ]),
+                Make<FixedLengthList2<FixedLengthList2<int>>, FixedLengthList2<int>>([
+                    Make<FixedLengthList2<int>, int>([-1, 3]),
+                    Make<FixedLengthList2<int>, int>([-2_000_000, 4711]),
+                ]));
+            AssertEqual<FixedLengthList2<uint>, uint>(a[0], [1, 5]);
+            AssertEqual<FixedLengthList2<uint>, uint>(a[1], [42, 1_000_000]);
+            AssertEqual<FixedLengthList2<int>, int>(b[0], [-1, 3]);
+            AssertEqual<FixedLengthList2<int>, int>(b[1], [-2_000_000, 4711]);
+        }
+        {
+            int[][] expected = [
+                [-1, 3, -2, 4],
+                [-2_000_000, 4711, 99_999, -5],
+                [-6, 7, 8, -9],
+                [50, -5, 500, -5000],
+            ];
+            var (a, b) = IToTestImports.LargeRoundtrip(
+                Make<FixedLengthList2<FixedLengthList2<uint>>, FixedLengthList2<uint>>([
+                    Make<FixedLengthList2<uint>, uint>([1, 5]),
+                    Make<FixedLengthList2<uint>, uint>([42, 1_000_000]),
+                ]),
+                Make<FixedLengthList4<FixedLengthList4<int>>, FixedLengthList4<int>>(
+                    expected.Select(row => Make<FixedLengthList4<int>, int>(row)).ToArray()));
+            AssertEqual<FixedLengthList2<uint>, uint>(a[0], [1, 5]);
+            AssertEqual<FixedLengthList2<uint>, uint>(a[1], [42, 1_000_000]);
+            for (int i = 0; i < expected.Length; i++)
+            {
+                AssertEqual<FixedLengthList4<int>, int>(b[i], expected[i]);
+            }
+        }
+        {
+            var result = IToTestImports.NightmareOnCpp(Make<FixedLengthList2<IToTestImports.Nested>, IToTestImports.Nested>([
+                new IToTestImports.Nested(Make<FixedLengthList2<int>, int>([1, -1])),
+                new IToTestImports.Nested(Make<FixedLengthList2<int>, int>([2, -2])),
+            ]));
+            AssertEqual<FixedLengthList2<int>, int>(result[0].l, [1, -1]);
+            AssertEqual<FixedLengthList2<int>, int>(result[1].l, [2, -2]);
+        }
+    }
+
+    static TList Make<TList, T>(ReadOnlySpan<T> values) where TList : struct
+    {
+        var list = new TList();
+        var span = System.Runtime.InteropServices.MemoryMarshal.CreateSpan(
+            ref System.Runtime.CompilerServices.Unsafe.As<TList, T>(ref list), values.Length);
+        values.CopyTo(span);
+        return list;
+    }
+
+    static void AssertEqual<TList, T>(TList list, ReadOnlySpan<T> expected) where TList : struct
+        where T : IEquatable<T>
+    {
+        var actual = System.Runtime.InteropServices.MemoryMarshal.CreateReadOnlySpan(
+            ref System.Runtime.CompilerServices.Unsafe.As<TList, T>(ref list), expected.Length);
+        Debug.Assert(actual.SequenceEqual(expected));
+    }
+}
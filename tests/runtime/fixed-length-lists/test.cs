@@ -0,0 +1,72 @@
+using System.Diagnostics;
+
+namespace TestWorld.wit.Exports.test.fixedLengthLists
+{
+    public class ToTestExportsImpl : IToTestExports
+    {
+        public static void ListParam(FixedLengthList4<uint> a)
+        {
+            Debug.Assert(((ReadOnlySpan<uint>)a).SequenceEqual([1, 2, 3, 4]));
+        }
+
+        public static void ListParam2(FixedLengthList2<FixedLengthList2<uint>> a)
+        {
+            var first = a[0];
+            var second = a[1];
+            Debug.Assert(((ReadOnlySpan<uint>)first).SequenceEqual([1, 2]));
+            Debug.Assert(((ReadOnlySpan<uint>)second).SequenceEqual([3, 4]));
+        }
+
+        public static void ListParam3(FixedLengthList20<int> a)
+        {
+            Debug.Assert(((ReadOnlySpan<int>)a).SequenceEqual(
+                [-1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16, -17, 18, -19, 20]));
+        }
+
+        public static FixedLengthList8<byte> ListResult()
+        {
+            var result = new FixedLengthList8<byte>();
+            ((ReadOnlySpan<byte>)[(byte)'0', (byte)'1', (byte)'A', (byte)'B', (byte)'a', (byte)'b', 128, 255])
+                .CopyTo(result);
+            return result;
+        }
+
+        public static (FixedLengthList4<ushort>, FixedLengthList4<short>) ListMinmax16(
+            FixedLengthList4<ushort> a,
+            FixedLengthList4<short> b)
+        {
+            return (a, b);
+        }
+
+        public static (FixedLengthList2<float>, FixedLengthList2<double>) ListMinmaxFloat(
+            FixedLengthList2<float> a,
+            FixedLengthList2<double> b)
+        {
+            return (a, b);
+        }
+
+        public static FixedLengthList12<byte> ListRoundtrip(FixedLengthList12<byte> a)
+        {
+            return a;
+        }
+
+        public static (FixedLengthList2<FixedLengthList2<uint>>, FixedLengthList2<FixedLengthList2<int>>) NestedRoundtrip(
+            FixedLengthList2<FixedLengthList2<uint>> a,
+            FixedLengthList2<FixedLengthList2<int>> b)
+        {
+            return (a, b);
+        }
+
+        public static (FixedLengthList2<FixedLengthList2<uint>>, FixedLengthList4<FixedLengthList4<int>>) LargeRoundtrip(
+            FixedLengthList2<FixedLengthList2<uint>> a,
+            FixedLengthList4<FixedLengthList4<int>> b)
+        {
+            return (a, b);
+        }
+
+        public static FixedLengthList2<IToTestExports.Nested> NightmareOnCpp(FixedLengthList2<IToTestExports.Nested> a)
+        {
+            return a;
+        }
+    }
+}
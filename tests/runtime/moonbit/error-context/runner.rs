@@ -0,0 +1,20 @@
+//@ wasmtime-flags = '-Wcomponent-model-error-context'
+
+include!(env!("BINDINGS"));
+
+use crate::test::moonbit_error_context::errors::*;
+use wit_bindgen::rt::async_support::ErrorContext;
+
+struct Component;
+
+export!(Component);
+
+impl Guest for Component {
+    fn run() {
+        let err = fail("disk full").unwrap_err();
+        assert_eq!(err.debug_message(), "disk full");
+
+        assert_eq!(describe(ErrorContext::new("from rust")), "from rust");
+        assert_eq!(describe(ErrorContext::new("π ≈ 3.14")), "π ≈ 3.14");
+    }
+}
@@ -0,0 +1,28 @@
+include!(env!("BINDINGS"));
+
+use crate::exports::my::test::shapes::{Color, Guest, Perms, Pixel, Point, Shape};
+
+struct Component;
+
+export!(Component);
+
+impl Guest for Component {
+    fn paint(p: Pixel) -> Shape {
+        if !p.perms.contains(Perms::WRITE) {
+            return Shape::Empty;
+        }
+        match p.color {
+            Color::Red => Shape::Dot(p.at),
+            Color::Green | Color::Blue => Shape::Line((Point { x: 0, y: 0 }, p.at)),
+        }
+    }
+
+    fn mirror(s: Shape) -> Shape {
+        let flip = |p: Point| Point { x: p.y, y: p.x };
+        match s {
+            Shape::Dot(p) => Shape::Dot(flip(p)),
+            Shape::Line((a, b)) => Shape::Line((flip(b), flip(a))),
+            Shape::Empty => Shape::Empty,
+        }
+    }
+}
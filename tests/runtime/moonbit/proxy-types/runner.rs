@@ -0,0 +1,27 @@
+//@ args = ['-dPartialEq']
+
+include!(env!("BINDINGS"));
+
+use crate::my::test::shapes::*;
+
+fn main() {
+    let at = Point { x: 1, y: 2 };
+
+    let pixel = |color, perms| Pixel { at, color, perms };
+    assert_eq!(paint(pixel(Color::Red, Perms::READ)), Shape::Empty);
+    assert_eq!(
+        paint(pixel(Color::Red, Perms::READ | Perms::WRITE)),
+        Shape::Dot(at),
+    );
+    assert_eq!(
+        paint(pixel(Color::Blue, Perms::WRITE | Perms::EXEC)),
+        Shape::Line((Point { x: 0, y: 0 }, at)),
+    );
+
+    assert_eq!(mirror(Shape::Dot(at)), Shape::Dot(Point { x: 2, y: 1 }));
+    assert_eq!(
+        mirror(Shape::Line((at, Point { x: 3, y: 4 }))),
+        Shape::Line((Point { x: 4, y: 3 }, Point { x: 2, y: 1 })),
+    );
+    assert_eq!(mirror(Shape::Empty), Shape::Empty);
+}
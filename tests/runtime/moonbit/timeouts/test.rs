@@ -0,0 +1,36 @@
+include!(env!("BINDINGS"));
+
+use crate::exports::test::moonbit_timeouts::slow::Guest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+struct Component;
+
+export!(Component);
+
+static HANG_DROP_COUNT: AtomicU32 = AtomicU32::new(0);
+
+struct CountDrop;
+
+impl Drop for CountDrop {
+    fn drop(&mut self) {
+        HANG_DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl Guest for Component {
+    async fn hang() {
+        let _guard = CountDrop;
+        std::future::pending::<()>().await;
+    }
+
+    fn hang_drop_count() -> u32 {
+        HANG_DROP_COUNT.load(Ordering::SeqCst)
+    }
+
+    async fn delayed(value: u32, yields: u32) -> u32 {
+        for _ in 0..yields {
+            wit_bindgen::yield_async().await;
+        }
+        value
+    }
+}
@@ -0,0 +1,32 @@
+include!(env!("BINDINGS"));
+
+use crate::test::wide_flags::to_test::*;
+
+struct Component;
+
+export!(Component);
+
+impl Guest for Component {
+    fn run() {
+        assert_eq!(
+            roundtrip_permissions(Permissions::empty()),
+            Permissions::empty()
+        );
+        assert_eq!(roundtrip_permissions(Permissions::all()), Permissions::all());
+
+        let perms = Permissions::P1 | Permissions::P33 | Permissions::P64 | Permissions::P69;
+        assert_eq!(roundtrip_permissions(perms), perms);
+
+        assert_eq!(
+            promote(Permissions::P0 | Permissions::P40),
+            Permissions::P40 | Permissions::P69
+        );
+
+        let holder = roundtrip_holder(Holder {
+            name: "admin".to_string(),
+            perms,
+        });
+        assert_eq!(holder.name, "admin");
+        assert_eq!(holder.perms, perms);
+    }
+}
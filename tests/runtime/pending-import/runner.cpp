@@ -0,0 +1,18 @@
+//@ wasmtime-flags = '-Wcomponent-model-async'
+
+#include <assert.h>
+#include <runner_cpp.h>
+
+wit::task<> exports::runner::Run() {
+  auto [writer, reader] = wit::future_new<wit::Void>();
+
+  // the export blocks on reading the future, the subtask stays pending
+  auto call = my::test::i::PendingImport(std::move(reader));
+  call.start();
+  assert(!call.done());
+
+  bool written = co_await std::move(writer).async_write(wit::Void{});
+  assert(written);
+  co_await call;
+  assert(call.done());
+}
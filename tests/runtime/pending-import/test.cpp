@@ -0,0 +1,7 @@
+#include <assert.h>
+#include <test_cpp.h>
+
+wit::task<> exports::my::test::i::PendingImport(wit::FutureReader<wit::Void> x) {
+  std::optional<wit::Void> value = co_await std::move(x);
+  assert(value.has_value());
+}
@@ -0,0 +1,44 @@
+//@ args = '--generate-value-methods'
+
+package export_wit_world
+
+import (
+	"encoding/json"
+	"fmt"
+	test "wit_component/test_records_to_test"
+)
+
+func Run() {
+	flags := test.RoundtripFlags2(test.F2C | test.F2E)
+	assertEqual(flags.String(), "c|e")
+	assertEqual(test.F2(0).String(), "")
+	assertEqual(roundtrip(flags), `["c","e"]`)
+
+	record := test.RoundtripRecord1(test.R1{A: 8, B: test.F1B})
+	assertEqual(roundtrip(record), `{"a":8,"b":["b"]}`)
+
+	var invalid test.F1
+	assert(json.Unmarshal([]byte(`["c"]`), &invalid) != nil)
+}
+
+// Marshals `value`, checking that it unmarshals back to the same value.
+func roundtrip[T comparable](value T) string {
+	data, err := json.Marshal(value)
+	assert(err == nil)
+	var result T
+	assert(json.Unmarshal(data, &result) == nil)
+	assertEqual(result, value)
+	return string(data)
+}
+
+func assert(v bool) {
+	if !v {
+		panic("assertion failed")
+	}
+}
+
+func assertEqual[T comparable](a T, b T) {
+	if a != b {
+		panic(fmt.Sprintf("%v not equal to %v", a, b))
+	}
+}
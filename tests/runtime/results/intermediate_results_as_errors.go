@@ -0,0 +1,46 @@
+//@ args = '--results-as-errors'
+
+package export_test_results_test
+
+import (
+	"errors"
+	"fmt"
+
+	imports "wit_component/test_results_test"
+
+	. "go.bytecodealliance.org/pkg/wit/types"
+)
+
+func StringError(x float32) (float32, error) {
+	return imports.StringError(x)
+}
+
+func EnumError(x float32) (float32, error) {
+	value, err := imports.EnumError(x)
+	if err != nil {
+		// The export unwraps this back to the `imports.E` payload.
+		return 0, fmt.Errorf("enum-error: %w", err)
+	}
+	return value, nil
+}
+
+func RecordError(x float32) (float32, error) {
+	return imports.RecordError(x)
+}
+
+func VariantError(x float32) (float32, error) {
+	return imports.VariantError(x)
+}
+
+func EmptyError(x uint32) (uint32, error) {
+	value, err := imports.EmptyError(x)
+	if err != nil {
+		// Any error is the `err` case of a `result` without a payload.
+		return 0, errors.New("empty-error")
+	}
+	return value, nil
+}
+
+func DoubleError(x uint32) (Result[Unit, string], error) {
+	return imports.DoubleError(x)
+}
@@ -0,0 +1,15 @@
+//@ wasmtime-flags = '-Wcomponent-model-async'
+
+#include <assert.h>
+#include <runner_cpp.h>
+
+wit::task<> exports::runner::Run() {
+  // awaiting an import starts the call, the export returns right away
+  co_await a::b::i::F();
+
+  // a started import runs concurrently with the caller
+  auto call = a::b::i::F();
+  call.start();
+  assert(call.done());
+  co_await call;
+}
@@ -0,0 +1,5 @@
+#include <test_cpp.h>
+
+wit::task<> exports::a::b::i::F() {
+  co_return;
+}
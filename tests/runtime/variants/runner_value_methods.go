@@ -0,0 +1,71 @@
+//@ args = '--generate-value-methods'
+
+package export_wit_world
+
+import (
+	"encoding/json"
+	"fmt"
+	test "wit_component/test_variants_to_test"
+
+	. "go.bytecodealliance.org/pkg/wit/types"
+)
+
+func Run() {
+	e1 := test.RoundtripEnum(test.E1B)
+	assertEqual(e1.String(), "b")
+	assertEqual(test.E1(7).String(), "E1(7)")
+	assertEqual(roundtrip(e1), `"b"`)
+
+	_, _, errno := test.VariantEnums(true, Ok[Unit, Unit](Unit{}), test.MyErrnoA)
+	assertEqual(errno.String(), "a")
+	assertEqual(roundtrip(errno), `"a"`)
+
+	a, b, _, _, _, _ := test.VariantCasts(test.Casts{
+		test.MakeC1A(1),
+		test.MakeC2B(2.5),
+		test.MakeC3A(3),
+		test.MakeC4A(4),
+		test.MakeC5A(5),
+		test.MakeC6A(6.0),
+	})
+	assertEqual(a.String(), "a(1)")
+	assertEqual(b.String(), "b(2.5)")
+	assertEqual(roundtrip(a), `{"tag":"a","value":1}`)
+	assertEqual(roundtrip(b), `{"tag":"b","value":2.5}`)
+
+	z1, _, _, _ := test.VariantZeros(test.Zeros{
+		test.MakeZ1B(),
+		test.MakeZ2B(),
+		test.MakeZ3B(),
+		test.MakeZ4B(),
+	})
+	assertEqual(z1.String(), "b")
+	assertEqual(roundtrip(z1), `{"tag":"b"}`)
+
+	var invalid test.E1
+	assert(json.Unmarshal([]byte(`"c"`), &invalid) != nil)
+	var invalidCase test.Z1
+	assert(json.Unmarshal([]byte(`{"tag":"c"}`), &invalidCase) != nil)
+}
+
+// Marshals `value`, checking that it unmarshals back to the same value.
+func roundtrip[T comparable](value T) string {
+	data, err := json.Marshal(value)
+	assert(err == nil)
+	var result T
+	assert(json.Unmarshal(data, &result) == nil)
+	assertEqual(result, value)
+	return string(data)
+}
+
+func assert(v bool) {
+	if !v {
+		panic("assertion failed")
+	}
+}
+
+func assertEqual[T comparable](a T, b T) {
+	if a != b {
+		panic(fmt.Sprintf("%v not equal to %v", a, b))
+	}
+}
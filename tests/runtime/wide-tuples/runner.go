@@ -0,0 +1,62 @@
+package export_wit_world
+
+import (
+	"fmt"
+	"slices"
+	test "wit_component/test_wide_tuples_to_test"
+)
+
+// Tuples wider than `Tuple16` are bound as anonymous structs.
+type anonymous = struct {
+	F0  uint8
+	F1  uint8
+	F2  uint8
+	F3  uint8
+	F4  uint8
+	F5  uint8
+	F6  uint8
+	F7  uint8
+	F8  uint8
+	F9  uint8
+	F10 uint8
+	F11 uint8
+	F12 uint8
+	F13 uint8
+	F14 uint8
+	F15 uint8
+	F16 string
+}
+
+func Run() {
+	wide := test.Wide{
+		F0: 1, F1: 2, F2: 3, F3: 4, F4: -5, F5: -6, F6: -7, F7: -8,
+		F8: 9.5, F9: 10.5, F10: 'x', F11: true, F12: "thirteen",
+		F13: 14, F14: 15, F15: 16, F16: 17,
+	}
+	{
+		r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11, r12, r13, r14, r15, r16 := test.WideRoundtrip(wide)
+		assertEqual(test.Wide{r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11, r12, r13, r14, r15, r16}, wide)
+	}
+
+	list := []test.Wide{wide, wide}
+	list[1].F12 = "other"
+	assert(slices.Equal(test.WideListRoundtrip(list), list))
+
+	{
+		value := anonymous{0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, "sixteen"}
+		r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11, r12, r13, r14, r15, r16 := test.AnonymousRoundtrip(value)
+		assertEqual(anonymous{r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11, r12, r13, r14, r15, r16}, value)
+	}
+}
+
+func assertEqual[T comparable](a T, b T) {
+	if a != b {
+		panic(fmt.Sprintf("%v not equal to %v", a, b))
+	}
+}
+
+func assert(v bool) {
+	if !v {
+		panic("assertion failed")
+	}
+}
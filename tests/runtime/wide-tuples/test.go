@@ -0,0 +1,31 @@
+package export_test_wide_tuples_to_test
+
+func WideRoundtrip(a Wide) (uint8, uint16, uint32, uint64, int8, int16, int32, int64, float32, float64, rune, bool, string, uint8, uint16, uint32, uint64) {
+	return a.F0, a.F1, a.F2, a.F3, a.F4, a.F5, a.F6, a.F7, a.F8, a.F9, a.F10, a.F11, a.F12, a.F13, a.F14, a.F15, a.F16
+}
+
+func WideListRoundtrip(a []Wide) []Wide {
+	return a
+}
+
+func AnonymousRoundtrip(a struct {
+	F0  uint8
+	F1  uint8
+	F2  uint8
+	F3  uint8
+	F4  uint8
+	F5  uint8
+	F6  uint8
+	F7  uint8
+	F8  uint8
+	F9  uint8
+	F10 uint8
+	F11 uint8
+	F12 uint8
+	F13 uint8
+	F14 uint8
+	F15 uint8
+	F16 string
+}) (uint8, uint8, uint8, uint8, uint8, uint8, uint8, uint8, uint8, uint8, uint8, uint8, uint8, uint8, uint8, uint8, string) {
+	return a.F0, a.F1, a.F2, a.F3, a.F4, a.F5, a.F6, a.F7, a.F8, a.F9, a.F10, a.F11, a.F12, a.F13, a.F14, a.F15, a.F16
+}